@@ -13,25 +13,21 @@ fn main() {
 
     let drawing = dxfio::Document::open(&dxf_path).unwrap();
 
-    let view_box = {
-        let min = &drawing
-            .headers
-            .iter()
-            .find(|node| node.node_type == "$LIMMIN")
-            .unwrap()
-            .atoms
-            .deref()
-            .get_point(0);
-        let max = &drawing
-            .headers
-            .iter()
-            .find(|node| node.node_type == "$LIMMAX")
-            .unwrap()
-            .atoms
-            .deref()
-            .get_point(0);
-        (min[0], min[1], max[0] - min[0], max[1] - min[1])
+    let limits = || {
+        let min = drawing.header("$LIMMIN")?.atoms.deref().get_point(0);
+        let max = drawing.header("$LIMMAX")?.atoms.deref().get_point(0);
+        Some([min, max])
+    };
+    let extents = || {
+        let bbox = drawing.extents(dxfio::Space::ModelSpace);
+        (!bbox.is_empty()).then_some([bbox.min, bbox.max])
     };
+    let [min, max] = drawing
+        .initial_view()
+        .or_else(extents)
+        .or_else(limits)
+        .unwrap_or([[0.0; 3], [1.0, 1.0, 0.0]]);
+    let view_box = (min[0], min[1], max[0] - min[0], max[1] - min[1]);
     let mut svg = svg::Document::new().set("viewBox", view_box).add(
         svg::node::element::Rectangle::new()
             .set("fill", "white")
@@ -40,8 +36,16 @@ fn main() {
             .set("width", view_box.2 - view_box.0)
             .set("height", view_box.3 - view_box.1),
     );
+    let clip_box = [
+        [view_box.0, view_box.1, f64::NEG_INFINITY],
+        [
+            view_box.0 + view_box.2,
+            view_box.1 + view_box.3,
+            f64::INFINITY,
+        ],
+    ];
     for entity in &drawing.entities {
-        svg = draw_entity(svg, entity, &drawing, &|p| {
+        svg = draw_entity(svg, entity, &drawing, &clip_box, &|p| {
             [p[0], 2.0 * view_box.1 + view_box.3 - p[1], p[2]]
         });
     }
@@ -52,12 +56,19 @@ fn draw_entity(
     svg: svg::Document,
     entity: &dxfio::EntityNode,
     doc: &dxfio::Document,
+    clip_box: &[[f64; 3]; 2],
     transform: &dyn Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
+    if doc
+        .layer(&entity.header.layer)
+        .is_some_and(|layer| !layer.is_visible())
+    {
+        return svg;
+    }
     match &entity.entity {
-        dxfio::Entity::Insert(insert) => draw_insert(svg, insert, doc, transform),
-        dxfio::Entity::Dimension(dim) => draw_dimension(svg, dim, doc, transform),
-        dxfio::Entity::Text(text) => draw_text(svg, text, transform),
+        dxfio::Entity::Insert(insert) => draw_insert(svg, insert, doc, clip_box, transform),
+        dxfio::Entity::Dimension(dim) => draw_dimension(svg, dim, doc, clip_box, transform),
+        dxfio::Entity::Text(text) => draw_text(svg, text, doc, transform),
         dxfio::Entity::MText(mtext) => draw_mtext(svg, mtext, transform),
         dxfio::Entity::Point(_) => {
             log::warn!("draw_entity() for Point entity: unimplemented");
@@ -65,11 +76,51 @@ fn draw_entity(
         }
         dxfio::Entity::Line(line) => draw_line(svg, line, transform),
         dxfio::Entity::Circle(cir) => draw_circle(svg, cir, transform),
-        dxfio::Entity::Arc(_) => {
-            log::warn!("draw_entity() for Arc entity: unimplemented");
+        dxfio::Entity::Arc(arc) => draw_curve(svg, arc, transform),
+        dxfio::Entity::Ellipse(ellipse) => draw_curve(svg, ellipse, transform),
+        dxfio::Entity::LwPolyline(pol) => draw_lw_polyline(svg, pol, transform),
+        dxfio::Entity::Polyline(pol) if pol.is_mesh() => {
+            log::warn!("draw_entity() for polygon and polyface meshes: unimplemented");
             svg
         }
-        dxfio::Entity::LwPolyline(pol) => draw_lw_polyline(svg, pol, transform),
+        dxfio::Entity::Polyline(pol) => draw_curve(svg, pol, transform),
+        dxfio::Entity::Spline(spline) => draw_curve(svg, spline, transform),
+        dxfio::Entity::Ray(ray) => draw_ray(svg, ray, clip_box, transform),
+        dxfio::Entity::XLine(xline) => draw_xline(svg, xline, clip_box, transform),
+        dxfio::Entity::Image(image) => {
+            draw_image_frame(svg, &image.boundary_wcs(), Some("gray"), transform)
+        }
+        dxfio::Entity::Wipeout(wipeout) => {
+            draw_image_frame(svg, &wipeout.wipeout_boundary_wcs(), None, transform)
+        }
+        dxfio::Entity::Viewport(viewport) => {
+            let frame = viewport.frame();
+            let data = points_to_pathdata(&frame.map(|p| transform(&p))).close();
+            svg.add(create_path(data).set("stroke", "gray"))
+        }
+        dxfio::Entity::MLine(mline) => {
+            if let Some(style) = doc.mline_style(mline) {
+                mline.polylines(style).iter().fold(svg, |svg, points| {
+                    line_strip(svg, &points.iter().map(transform).collect::<Vec<_>>(), None)
+                })
+            } else {
+                log::warn!("MLINESTYLE not found: {}", mline.style_name);
+                svg
+            }
+        }
+        dxfio::Entity::Region(_)
+        | dxfio::Entity::Solid3d(_)
+        | dxfio::Entity::Body(_)
+        | dxfio::Entity::Surface(_) => {
+            log::warn!("draw_entity() for ACIS entity: unimplemented");
+            svg
+        }
+        dxfio::Entity::Mesh(mesh) => mesh.polygons().iter().fold(svg, |svg, polygon| {
+            let mut polygon = polygon.iter().map(transform).collect::<Vec<_>>();
+            polygon.extend(polygon.first().copied());
+            line_strip(svg, &polygon, None)
+        }),
+        dxfio::Entity::Hatch(hatch) => draw_hatch(svg, hatch, transform),
         dxfio::Entity::NotSupported(entity_type, _) => {
             log::warn!("not supported entity type: {}", entity_type);
             svg
@@ -77,42 +128,53 @@ fn draw_entity(
     }
 }
 
-fn draw_insert(
+fn draw_hatch(
     mut svg: svg::Document,
-    insert: &dxfio::Insert,
-    doc: &dxfio::Document,
+    hatch: &dxfio::Hatch,
     transform: &dyn Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
-    if let Some(block) = doc
-        .blocks
-        .iter()
-        .find(|block| block.block_name == insert.block_name)
-    {
-        let (cos, sin) = {
-            let theta = insert.rotation_degree * std::f64::consts::PI / 180.0;
-            (theta.cos(), theta.sin())
-        };
-        let transform = |p: &[f64; 3]| {
-            let p = [
-                insert.scale_factor[0] * p[0],
-                insert.scale_factor[1] * p[1],
-                insert.scale_factor[2] * p[2],
-            ];
-            let p = [cos * p[0] - sin * p[1], sin * p[0] + cos * p[1], p[2]];
-            let p = [
-                insert.insertion_point[0] + p[0],
-                insert.insertion_point[1] + p[1],
-                insert.insertion_point[2] + p[2],
-            ];
-            transform(&p)
-        };
-        for entity in &block.entities {
-            svg = draw_entity(svg, entity, doc, &transform);
+    let resolution = dxfio::Resolution::SegmentsPerTurn(64);
+    if hatch.is_solid_fill {
+        for polygon in hatch.fill_polygons(&resolution) {
+            let data = polygon.iter().filter(|ring| !ring.is_empty()).fold(
+                svg::node::element::path::Data::new(),
+                |data, ring| {
+                    let ring = ring.iter().map(transform).collect::<Vec<_>>();
+                    ring[1..]
+                        .iter()
+                        .fold(data.move_to((ring[0][0], ring[0][1])), |data, p| {
+                            data.line_to((p[0], p[1]))
+                        })
+                        .close()
+                },
+            );
+            svg = svg.add(
+                create_path(data)
+                    .set("fill", "gray")
+                    .set("fill-rule", "evenodd"),
+            );
         }
+        svg
     } else {
-        println!("block not found: name = {}", insert.block_name);
+        hatch
+            .pattern_segments(&resolution)
+            .iter()
+            .fold(svg, |svg, segment| {
+                line_strip(svg, &segment.map(|p| transform(&p)), None)
+            })
     }
-    svg
+}
+
+fn draw_insert(
+    svg: svg::Document,
+    insert: &dxfio::Insert,
+    doc: &dxfio::Document,
+    clip_box: &[[f64; 3]; 2],
+    transform: &dyn Fn(&[f64; 3]) -> [f64; 3],
+) -> svg::Document {
+    doc.explode_insert(insert).iter().fold(svg, |svg, entity| {
+        draw_entity(svg, entity, doc, clip_box, transform)
+    })
 }
 
 fn points_to_pathdata(pol: &[[f64; 3]]) -> svg::node::element::path::Data {
@@ -142,6 +204,7 @@ fn draw_dimension(
     mut svg: svg::Document,
     dim: &dxfio::Dimension,
     doc: &dxfio::Document,
+    clip_box: &[[f64; 3]; 2],
     transform: impl Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
     if let Some(block) = doc
@@ -150,53 +213,60 @@ fn draw_dimension(
         .find(|block| block.block_name == dim.block_name)
     {
         for entity in &block.entities {
-            svg = draw_entity(svg, entity, doc, &transform);
+            svg = draw_entity(svg, entity, doc, clip_box, &transform);
+        }
+        svg
+    } else if let Some(style) = doc.effective_dim_style(dim) {
+        let graphics = dim.graphics(&style);
+        for line in &graphics.lines {
+            svg = line_strip(svg, &line.map(|p| transform(&p)), Some("blue"));
+        }
+        for arc in &graphics.arcs {
+            let (start, end) = (arc.start_degree.to_radians(), arc.end_degree.to_radians());
+            let points = (0..=32)
+                .map(|i| {
+                    let theta = start + (end - start) * i as f64 / 32.0;
+                    let [x, y, z] = arc.circle.center;
+                    let r = arc.circle.radius;
+                    transform(&[x + r * theta.cos(), y + r * theta.sin(), z])
+                })
+                .collect::<Vec<_>>();
+            svg = line_strip(svg, &points, Some("blue"));
+        }
+        for arrowhead in &graphics.arrowheads {
+            let data = points_to_pathdata(&arrowhead.map(|p| transform(&p))).close();
+            svg = svg.add(create_path(data).set("stroke", "blue").set("fill", "blue"));
+        }
+        if let Some(text) = &graphics.text {
+            svg = draw_mtext(svg, text, &transform);
         }
         svg
     } else {
-        let p1 = &dim.definition_point;
-        let p2 = dim.definition_point2.as_ref().unwrap_or(&[0.0, 0.0, 0.0]);
-        let p3 = dim.definition_point3.as_ref().unwrap_or(&[0.0, 0.0, 0.0]);
-        let p4 = {
-            let theta = dim.rotation_angle.unwrap_or(0.0) * std::f64::consts::PI / 180.0;
-            let line1 = geom2d::Line {
-                p: p1.into(),
-                v: geom2d::UnitVec::of_angle(theta),
-            };
-            let line2 = geom2d::Line {
-                p: p2.into(),
-                v: (geom2d::Pos::from(p1) - geom2d::Pos::from(p3))
-                    .normalize()
-                    .unwrap(),
-            };
-            line1.intersection_pos(&line2).unwrap()
-        };
-        line_strip(
-            svg,
-            &[
-                transform(p2),
-                transform(&p4.into()),
-                transform(p1),
-                transform(p3),
-            ],
-            Some("blue"),
-        )
+        log::warn!("dimension style not found: {}", dim.dimension_style);
+        svg
     }
 }
 
 fn draw_text(
     svg: svg::Document,
     src: &dxfio::Text,
+    doc: &dxfio::Document,
     transform: impl Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
     let scale = (transform(&[1.0, 0.0, 0.0])[0] - transform(&[0.0, 0.0, 0.0])[0]).abs();
     // println!("draw_text(): scale = {}", scale);
+    let font = doc.text_font(src);
     let p = transform(&src.point1);
     let text = svg::node::element::Text::new()
         .set("x", p[0])
         .set("y", p[1])
-        .set("font-size", scale * src.height)
+        .set("font-size", scale * font.height)
         .add(svg::node::Text::new(src.text.clone()));
+    let text = if let Some(family) = &font.true_type_family {
+        text.set("font-family", family.as_str())
+    } else {
+        text
+    };
     let text = if let Some(deg) = src.rotation_degree {
         println!("draw_text(): rotation_degree = {}", deg);
         text.set("transform", format!("rotate({} {} {})", -deg, p[0], p[1]))
@@ -280,16 +350,83 @@ fn draw_lw_polyline(
     pol: &dxfio::LwPolyline,
     transform: impl Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
-    let points = pol
-        .vertices
+    draw_curve(svg, pol, transform)
+}
+
+fn draw_curve(
+    svg: svg::Document,
+    curve: &impl dxfio::Tessellate,
+    transform: impl Fn(&[f64; 3]) -> [f64; 3],
+) -> svg::Document {
+    let points = curve
+        .tessellate(&dxfio::Resolution::SegmentsPerTurn(64))
         .iter()
-        .map(|v| transform(&[v.coord[0], v.coord[1], 0.0]))
+        .map(&transform)
         .collect::<Vec<_>>();
-    let mut data = points_to_pathdata(&points);
-    if pol.is_closed {
-        data = data.close();
+    line_strip(svg, &points, None)
+}
+
+// clip_box is given in the SVG coordinate system, so clipping is done after the transform.
+fn transform_direction(
+    p: &[f64; 3],
+    v: &[f64; 3],
+    transform: impl Fn(&[f64; 3]) -> [f64; 3],
+) -> ([f64; 3], [f64; 3]) {
+    let p0 = transform(p);
+    let p1 = transform(&[p[0] + v[0], p[1] + v[1], p[2] + v[2]]);
+    (p0, [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]])
+}
+
+fn draw_ray(
+    svg: svg::Document,
+    ray: &dxfio::Ray,
+    clip_box: &[[f64; 3]; 2],
+    transform: impl Fn(&[f64; 3]) -> [f64; 3],
+) -> svg::Document {
+    let (base_point, unit_direction) =
+        transform_direction(&ray.base_point, &ray.unit_direction, transform);
+    let ray = dxfio::Ray {
+        base_point,
+        unit_direction,
+    };
+    if let Some(segment) = ray.clip(&clip_box[0], &clip_box[1]) {
+        svg.add(create_path(points_to_pathdata(&segment)))
+    } else {
+        svg
+    }
+}
+
+fn draw_xline(
+    svg: svg::Document,
+    xline: &dxfio::XLine,
+    clip_box: &[[f64; 3]; 2],
+    transform: impl Fn(&[f64; 3]) -> [f64; 3],
+) -> svg::Document {
+    let (base_point, unit_direction) =
+        transform_direction(&xline.base_point, &xline.unit_direction, transform);
+    let xline = dxfio::XLine {
+        base_point,
+        unit_direction,
+    };
+    if let Some(segment) = xline.clip(&clip_box[0], &clip_box[1]) {
+        svg.add(create_path(points_to_pathdata(&segment)))
+    } else {
+        svg
+    }
+}
+
+fn draw_image_frame(
+    svg: svg::Document,
+    boundary: &[[f64; 3]],
+    color: Option<&'static str>,
+    transform: impl Fn(&[f64; 3]) -> [f64; 3],
+) -> svg::Document {
+    let boundary = boundary.iter().map(transform).collect::<Vec<_>>();
+    let data = points_to_pathdata(&boundary).close();
+    match color {
+        Some(color) => svg.add(create_path(data).set("stroke", color)),
+        None => svg.add(create_path(data).set("fill", "white").set("stroke", "none")),
     }
-    svg.add(create_path(data))
 }
 
 fn draw_circle(
@@ -297,7 +434,7 @@ fn draw_circle(
     cir: &dxfio::Circle,
     transform: impl Fn(&[f64; 3]) -> [f64; 3],
 ) -> svg::Document {
-    let center = transform(&cir.center);
+    let center = transform(&cir.center_wcs());
     let element = svg::node::element::Circle::new()
         .set("cx", center[0])
         .set("cy", center[1])
@@ -88,7 +88,7 @@ impl Vec {
     }
     pub fn normalize(&self) -> Option<UnitVec> {
         let v = *self / self.norm();
-        v.is_finite().then(|| UnitVec(v))
+        v.is_finite().then_some(UnitVec(v))
     }
 }
 
@@ -145,7 +145,7 @@ impl Matrix {
             [self[1][1] / det, -self[0][1] / det],
             [-self[1][0] / det, self[0][0] / det],
         ]);
-        inv.is_finite().then(|| inv)
+        inv.is_finite().then_some(inv)
     }
 }
 
@@ -0,0 +1,153 @@
+use crate::*;
+
+// The standard AutoCAD Color Index palette. 0 (ByBlock) is black and 7 is white.
+#[rustfmt::skip]
+const ACI_PALETTE: [[u8; 3]; 256] = [
+    [0, 0, 0], [255, 0, 0], [255, 255, 0], [0, 255, 0], // 0
+    [0, 255, 255], [0, 0, 255], [255, 0, 255], [255, 255, 255], // 4
+    [65, 65, 65], [128, 128, 128], [255, 0, 0], [255, 170, 170], // 8
+    [189, 0, 0], [189, 126, 126], [129, 0, 0], [129, 86, 86], // 12
+    [104, 0, 0], [104, 69, 69], [79, 0, 0], [79, 53, 53], // 16
+    [255, 63, 0], [255, 191, 170], [189, 47, 0], [189, 141, 126], // 20
+    [129, 32, 0], [129, 96, 86], [104, 26, 0], [104, 77, 69], // 24
+    [79, 19, 0], [79, 59, 53], [255, 127, 0], [255, 212, 170], // 28
+    [189, 94, 0], [189, 157, 126], [129, 64, 0], [129, 107, 86], // 32
+    [104, 52, 0], [104, 86, 69], [79, 39, 0], [79, 66, 53], // 36
+    [255, 191, 0], [255, 233, 170], [189, 141, 0], [189, 173, 126], // 40
+    [129, 96, 0], [129, 118, 86], [104, 78, 0], [104, 95, 69], // 44
+    [79, 59, 0], [79, 72, 53], [255, 255, 0], [255, 255, 170], // 48
+    [189, 189, 0], [189, 189, 126], [129, 129, 0], [129, 129, 86], // 52
+    [104, 104, 0], [104, 104, 69], [79, 79, 0], [79, 79, 53], // 56
+    [191, 255, 0], [233, 255, 170], [141, 189, 0], [173, 189, 126], // 60
+    [96, 129, 0], [118, 129, 86], [78, 104, 0], [95, 104, 69], // 64
+    [59, 79, 0], [72, 79, 53], [127, 255, 0], [212, 255, 170], // 68
+    [94, 189, 0], [157, 189, 126], [64, 129, 0], [107, 129, 86], // 72
+    [52, 104, 0], [86, 104, 69], [39, 79, 0], [66, 79, 53], // 76
+    [63, 255, 0], [191, 255, 170], [47, 189, 0], [141, 189, 126], // 80
+    [32, 129, 0], [96, 129, 86], [26, 104, 0], [77, 104, 69], // 84
+    [19, 79, 0], [59, 79, 53], [0, 255, 0], [170, 255, 170], // 88
+    [0, 189, 0], [126, 189, 126], [0, 129, 0], [86, 129, 86], // 92
+    [0, 104, 0], [69, 104, 69], [0, 79, 0], [53, 79, 53], // 96
+    [0, 255, 63], [170, 255, 191], [0, 189, 47], [126, 189, 141], // 100
+    [0, 129, 32], [86, 129, 96], [0, 104, 26], [69, 104, 77], // 104
+    [0, 79, 19], [53, 79, 59], [0, 255, 127], [170, 255, 212], // 108
+    [0, 189, 94], [126, 189, 157], [0, 129, 64], [86, 129, 107], // 112
+    [0, 104, 52], [69, 104, 86], [0, 79, 39], [53, 79, 66], // 116
+    [0, 255, 191], [170, 255, 233], [0, 189, 141], [126, 189, 173], // 120
+    [0, 129, 96], [86, 129, 118], [0, 104, 78], [69, 104, 95], // 124
+    [0, 79, 59], [53, 79, 72], [0, 255, 255], [170, 255, 255], // 128
+    [0, 189, 189], [126, 189, 189], [0, 129, 129], [86, 129, 129], // 132
+    [0, 104, 104], [69, 104, 104], [0, 79, 79], [53, 79, 79], // 136
+    [0, 191, 255], [170, 233, 255], [0, 141, 189], [126, 173, 189], // 140
+    [0, 96, 129], [86, 118, 129], [0, 78, 104], [69, 95, 104], // 144
+    [0, 59, 79], [53, 72, 79], [0, 127, 255], [170, 212, 255], // 148
+    [0, 94, 189], [126, 157, 189], [0, 64, 129], [86, 107, 129], // 152
+    [0, 52, 104], [69, 86, 104], [0, 39, 79], [53, 66, 79], // 156
+    [0, 63, 255], [170, 191, 255], [0, 47, 189], [126, 141, 189], // 160
+    [0, 32, 129], [86, 96, 129], [0, 26, 104], [69, 77, 104], // 164
+    [0, 19, 79], [53, 59, 79], [0, 0, 255], [170, 170, 255], // 168
+    [0, 0, 189], [126, 126, 189], [0, 0, 129], [86, 86, 129], // 172
+    [0, 0, 104], [69, 69, 104], [0, 0, 79], [53, 53, 79], // 176
+    [63, 0, 255], [191, 170, 255], [47, 0, 189], [141, 126, 189], // 180
+    [32, 0, 129], [96, 86, 129], [26, 0, 104], [77, 69, 104], // 184
+    [19, 0, 79], [59, 53, 79], [127, 0, 255], [212, 170, 255], // 188
+    [94, 0, 189], [157, 126, 189], [64, 0, 129], [107, 86, 129], // 192
+    [52, 0, 104], [86, 69, 104], [39, 0, 79], [66, 53, 79], // 196
+    [191, 0, 255], [233, 170, 255], [141, 0, 189], [173, 126, 189], // 200
+    [96, 0, 129], [118, 86, 129], [78, 0, 104], [95, 69, 104], // 204
+    [59, 0, 79], [72, 53, 79], [255, 0, 255], [255, 170, 255], // 208
+    [189, 0, 189], [189, 126, 189], [129, 0, 129], [129, 86, 129], // 212
+    [104, 0, 104], [104, 69, 104], [79, 0, 79], [79, 53, 79], // 216
+    [255, 0, 191], [255, 170, 233], [189, 0, 141], [189, 126, 173], // 220
+    [129, 0, 96], [129, 86, 118], [104, 0, 78], [104, 69, 95], // 224
+    [79, 0, 59], [79, 53, 72], [255, 0, 127], [255, 170, 212], // 228
+    [189, 0, 94], [189, 126, 157], [129, 0, 64], [129, 86, 107], // 232
+    [104, 0, 52], [104, 69, 86], [79, 0, 39], [79, 53, 66], // 236
+    [255, 0, 63], [255, 170, 191], [189, 0, 47], [189, 126, 141], // 240
+    [129, 0, 32], [129, 86, 96], [104, 0, 26], [104, 69, 77], // 244
+    [79, 0, 19], [79, 53, 59], [51, 51, 51], [80, 80, 80], // 248
+    [105, 105, 105], [130, 130, 130], [190, 190, 190], [255, 255, 255], // 252
+];
+
+impl Rgb {
+    /// Color of an AutoCAD Color Index.
+    pub fn from_aci(index: u8) -> Self {
+        let [r, g, b] = ACI_PALETTE[index as usize];
+        Self { r, g, b }
+    }
+
+    /// The AutoCAD Color Index (1..=255) closest to this color.
+    pub fn nearest_aci(&self) -> u8 {
+        let distance = |[r, g, b]: [u8; 3]| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(r, self.r) + d(g, self.g) + d(b, self.b)
+        };
+        (1..=255u8)
+            .min_by_key(|&i| distance(ACI_PALETTE[i as usize]))
+            .unwrap_or(7)
+    }
+}
+
+impl ColorNumber {
+    /// RGB of an explicit color index; `None` for ByLayer, ByBlock, etc.
+    pub fn rgb(&self) -> Option<Rgb> {
+        match self {
+            ColorNumber::Number(i) => Some(Rgb::from_aci(*i)),
+            _ => None,
+        }
+    }
+}
+
+impl EntityHeader {
+    /// RGB of the entity's own color: the true color (420) if any, otherwise its color index.
+    /// A book color (430) is always accompanied by its true color.
+    pub fn rgb(&self) -> Option<Rgb> {
+        self.color_rgb.or_else(|| self.color_number.rgb())
+    }
+
+    /// Color book and color name of a book color ("BOOK$NAME").
+    pub fn book_color(&self) -> Option<(&str, &str)> {
+        let name = self.color_name.as_deref()?;
+        match name.split_once('$') {
+            Some((book, color)) => Some((book, color)),
+            None => Some(("", name)),
+        }
+    }
+}
+
+impl EffectiveProperties {
+    pub fn rgb(&self) -> Rgb {
+        self.color_rgb
+            .unwrap_or_else(|| Rgb::from_aci(self.color_number))
+    }
+}
+
+#[test]
+fn test_aci() {
+    assert_eq!(Rgb::from_aci(1), Rgb { r: 255, g: 0, b: 0 });
+    assert_eq!(
+        Rgb::from_aci(31),
+        Rgb {
+            r: 255,
+            g: 212,
+            b: 170
+        }
+    );
+    assert_eq!(Rgb { r: 250, g: 5, b: 3 }.nearest_aci(), 1);
+    assert_eq!(
+        Rgb {
+            r: 0x81,
+            g: 0x56,
+            b: 0x56
+        }
+        .nearest_aci(),
+        15
+    );
+    let header = EntityHeader {
+        color_number: ColorNumber::Number(3),
+        color_name: Some("RAL CLASSIC$RAL 1000".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(header.rgb(), Some(Rgb { r: 0, g: 255, b: 0 }));
+    assert_eq!(header.book_color(), Some(("RAL CLASSIC", "RAL 1000")));
+}
@@ -0,0 +1,587 @@
+use super::{add, cross, normalize, scale, Ocs, PolylineSegment, Resolution, Tessellate};
+use crate::*;
+use std::f64::consts::PI;
+
+/// Axis-aligned bounding box in WCS.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BoundingBox {
+    pub min: [f64; 3],
+    pub max: [f64; 3],
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl BoundingBox {
+    pub const EMPTY: Self = Self {
+        min: [f64::INFINITY; 3],
+        max: [f64::NEG_INFINITY; 3],
+    };
+
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a [f64; 3]>) -> Self {
+        let mut bbox = Self::EMPTY;
+        for p in points {
+            bbox.add_point(p);
+        }
+        bbox
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|i| self.min[i] > self.max[i])
+    }
+
+    pub fn add_point(&mut self, p: &[f64; 3]) {
+        for (i, &x) in p.iter().enumerate() {
+            self.min[i] = self.min[i].min(x);
+            self.max[i] = self.max[i].max(x);
+        }
+    }
+
+    pub fn add_box(&mut self, other: &Self) {
+        if !other.is_empty() {
+            self.add_point(&other.min);
+            self.add_point(&other.max);
+        }
+    }
+
+    pub fn size(&self) -> [f64; 3] {
+        [
+            self.max[0] - self.min[0],
+            self.max[1] - self.min[1],
+            self.max[2] - self.min[2],
+        ]
+    }
+
+    pub fn center(&self) -> [f64; 3] {
+        scale(&add(&self.min, &self.max), 0.5)
+    }
+
+    /// The box projected onto the XY plane.
+    pub fn xy(&self) -> [[f64; 2]; 2] {
+        [[self.min[0], self.min[1]], [self.max[0], self.max[1]]]
+    }
+
+    pub fn contains(&self, p: &[f64; 3]) -> bool {
+        (0..3).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    // the box swept along `v` (extrusion by thickness)
+    fn add_extrusion(&mut self, v: &[f64; 3]) {
+        if !self.is_empty() && *v != [0.0; 3] {
+            self.add_point(&add(&self.min, v));
+            self.add_point(&add(&self.max, v));
+        }
+    }
+
+    // the box grown by `radius` within the plane with the `normal`
+    fn add_planar_margin(&mut self, normal: &[f64; 3], radius: f64) {
+        if !self.is_empty() && radius > 0.0 {
+            let n = normalize(normal);
+            for (i, ni) in n.iter().enumerate() {
+                let margin = radius * (1.0 - ni * ni).max(0.0).sqrt();
+                self.min[i] -= margin;
+                self.max[i] += margin;
+            }
+        }
+    }
+}
+
+/// Exact box of the elliptical arc `center + u cos(t) + v sin(t)` for `t` in `start..=end` (radian).
+pub(crate) fn elliptical_arc_box(
+    center: &[f64; 3],
+    u: &[f64; 3],
+    v: &[f64; 3],
+    start: f64,
+    end: f64,
+) -> BoundingBox {
+    let point = |t: f64| {
+        let (sin, cos) = t.sin_cos();
+        add(center, &add(&scale(u, cos), &scale(v, sin)))
+    };
+    let mut bbox = BoundingBox::from_points(&[point(start), point(end)]);
+    // extrema of each coordinate: -u[i] sin(t) + v[i] cos(t) = 0
+    for i in 0..3 {
+        let t0 = v[i].atan2(u[i]);
+        for t in [t0, t0 + PI] {
+            let t = start + (t - start).rem_euclid(2.0 * PI);
+            if t <= end {
+                bbox.add_point(&point(t));
+            }
+        }
+    }
+    bbox
+}
+
+impl Line {
+    pub fn bounding_box(&self) -> BoundingBox {
+        let mut bbox = BoundingBox::from_points(&[self.p1, self.p2]);
+        if let Some(n) = self.extrusion_direction {
+            bbox.add_extrusion(&scale(&normalize(&n), self.thickness));
+        } else {
+            bbox.add_extrusion(&[0.0, 0.0, self.thickness]);
+        }
+        bbox
+    }
+}
+
+impl Point {
+    pub fn bounding_box(&self) -> BoundingBox {
+        let mut bbox = BoundingBox::from_points(&[self.coord]);
+        let n = normalize(&self.extrusion_direction.unwrap_or([0.0, 0.0, 1.0]));
+        bbox.add_extrusion(&scale(&n, self.thickness));
+        bbox
+    }
+}
+
+impl Circle {
+    pub fn bounding_box(&self) -> BoundingBox {
+        self.arc_box(0.0, 2.0 * PI)
+    }
+
+    fn arc_box(&self, start: f64, end: f64) -> BoundingBox {
+        let ocs = self.ocs();
+        let u = scale(&ocs.axes[0], self.radius);
+        let v = scale(&ocs.axes[1], self.radius);
+        let mut bbox = elliptical_arc_box(&ocs.to_wcs(&self.center), &u, &v, start, end);
+        bbox.add_extrusion(&scale(&ocs.axes[2], self.thickness));
+        bbox
+    }
+}
+
+impl Arc {
+    pub fn bounding_box(&self) -> BoundingBox {
+        let start = self.start_degree.to_radians();
+        let end = start
+            + (self.end_degree - self.start_degree)
+                .rem_euclid(360.0)
+                .to_radians();
+        self.circle.arc_box(start, end)
+    }
+}
+
+impl Ellipse {
+    pub fn bounding_box(&self) -> BoundingBox {
+        let (start, mut end) = (self.start_parameter, self.end_parameter);
+        while end <= start {
+            end += 2.0 * PI;
+        }
+        elliptical_arc_box(
+            &self.center,
+            &self.major_axis,
+            &self.minor_axis(),
+            start,
+            end,
+        )
+    }
+}
+
+impl LwPolyline {
+    /// The box of the center line, grown by half of the largest width.
+    pub fn bounding_box(&self) -> BoundingBox {
+        let ocs = self.ocs();
+        let z = self.elevation.unwrap_or(0.0);
+        let to_wcs = |p: &[f64; 2]| ocs.to_wcs(&[p[0], p[1], z]);
+        let mut bbox = BoundingBox::from_points(
+            &self
+                .vertices
+                .iter()
+                .map(|v| to_wcs(&v.coord))
+                .collect::<Vec<_>>(),
+        );
+        for segment in self.segments() {
+            if let PolylineSegment::Arc {
+                center,
+                radius,
+                start_radian,
+                sweep_radian,
+                ..
+            } = segment
+            {
+                let (start, end) = if sweep_radian < 0.0 {
+                    (start_radian + sweep_radian, start_radian)
+                } else {
+                    (start_radian, start_radian + sweep_radian)
+                };
+                bbox.add_box(&elliptical_arc_box(
+                    &to_wcs(&center),
+                    &scale(&ocs.axes[0], radius),
+                    &scale(&ocs.axes[1], radius),
+                    start,
+                    end,
+                ));
+            }
+        }
+        let width = self
+            .vertices
+            .iter()
+            .flat_map(|v| v.start_width.into_iter().chain(v.end_width))
+            .chain(self.constant_width)
+            .fold(0.0, f64::max);
+        bbox.add_planar_margin(&ocs.axes[2], width / 2.0);
+        bbox.add_extrusion(&scale(&ocs.axes[2], self.thickness.unwrap_or(0.0)));
+        bbox
+    }
+}
+
+impl Image {
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.boundary_wcs())
+    }
+}
+
+impl Mesh {
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.vertices)
+    }
+}
+
+impl Hatch {
+    /// Box of the boundary loops; curved edges are approximated by one-degree segments.
+    pub fn bounding_box(&self) -> BoundingBox {
+        let ocs = self.ocs();
+        let z = self.elevation_point[2];
+        let points = self
+            .boundary_loops(&Resolution::SegmentsPerTurn(360))
+            .into_iter()
+            .flatten()
+            .map(|p| ocs.to_wcs(&[p[0], p[1], z]))
+            .collect::<Vec<_>>();
+        BoundingBox::from_points(&points)
+    }
+}
+
+impl Spline {
+    /// Box of the curve approximated by 90 segments per knot span.
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.tessellate(&Resolution::SegmentsPerTurn(360)))
+    }
+}
+
+impl Polyline {
+    /// 2D polylines are bounded as LWPOLYLINEs, and meshes by their vertices.
+    pub fn bounding_box(&self) -> BoundingBox {
+        if self.is_mesh() {
+            // polyface meshes also have vertices holding only the indices of a face
+            let points = self
+                .vertices
+                .iter()
+                .filter(|v| v.flags.is_polygon_mesh_vertex || !v.flags.is_polyface_mesh_vertex)
+                .map(|v| v.coord)
+                .collect::<Vec<_>>();
+            return BoundingBox::from_points(&points);
+        }
+        match self.to_lw_polyline() {
+            Some(polyline) if self.spline_frame().is_none() => polyline.bounding_box(),
+            _ => BoundingBox::from_points(&self.tessellate(&Resolution::SegmentsPerTurn(360))),
+        }
+    }
+}
+
+impl ViewportEntity {
+    /// The box of the viewport frame in paper space.
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.frame())
+    }
+}
+
+// box of a text rectangle given by its local extents along the text direction `x` and `y`
+fn text_box(
+    origin: &[f64; 3],
+    x: &[f64; 3],
+    y: &[f64; 3],
+    xs: [f64; 2],
+    ys: [f64; 2],
+) -> BoundingBox {
+    let mut bbox = BoundingBox::EMPTY;
+    for &a in &xs {
+        for &b in &ys {
+            bbox.add_point(&add(origin, &add(&scale(x, a), &scale(y, b))));
+        }
+    }
+    bbox
+}
+
+// approximate text width: every character is assumed to be as wide as it is high
+fn estimated_width(text: &str, font: &TextFont) -> f64 {
+    text.chars().count() as f64 * font.height * font.width_factor
+}
+
+impl Document {
+    /// Approximate box of a TEXT entity, estimated from the number of characters.
+    fn text_bounding_box(&self, text: &Text) -> BoundingBox {
+        let font = self.text_font(text);
+        let ocs = text.ocs();
+        let h = font.height;
+        let mut width = estimated_width(&text.text, &font);
+        let rotation = text.rotation_degree.unwrap_or(0.0);
+        let (origin, x_offset, y_range) = match text.alignment {
+            TextAlignment::Combo(horizontal, vertical) => {
+                let x_offset = match horizontal {
+                    TextHorizontalAlignment::Left => 0.0,
+                    TextHorizontalAlignment::Center => -width / 2.0,
+                    TextHorizontalAlignment::Right => -width,
+                };
+                let y_range = match vertical {
+                    TextVerticalAlignment::Baseline | TextVerticalAlignment::Bottom => [0.0, h],
+                    TextVerticalAlignment::Middle => [-h / 2.0, h / 2.0],
+                    TextVerticalAlignment::Top => [-h, 0.0],
+                };
+                let is_default = horizontal == TextHorizontalAlignment::Left
+                    && vertical == TextVerticalAlignment::Baseline;
+                let origin = if is_default { text.point1 } else { text.point2 };
+                (origin, x_offset, y_range)
+            }
+            TextAlignment::Middle => (text.point2, -width / 2.0, [-h / 2.0, h / 2.0]),
+            TextAlignment::Aligned | TextAlignment::Fit => {
+                width = super::norm(&super::sub(&text.point2, &text.point1));
+                (text.point1, 0.0, [0.0, h])
+            }
+        };
+        let origin = ocs.to_wcs(&origin);
+        let mut x = text.direction_wcs();
+        let mut y = ocs.direction_to_wcs(rotation + 90.0);
+        if font.mirror_flags.x {
+            x = scale(&x, -1.0);
+        }
+        if font.mirror_flags.y {
+            y = scale(&y, -1.0);
+        }
+        text_box(&origin, &x, &y, [x_offset, x_offset + width], y_range)
+    }
+
+    /// Approximate box of an MTEXT entity from its reference rectangle,
+    /// or from the number of lines and characters if the extents are not stored.
+    fn mtext_bounding_box(&self, mtext: &MText) -> BoundingBox {
+        let font = self.mtext_font(mtext);
+        let normal = mtext.extrusion_vector.unwrap_or([0.0, 0.0, 1.0]);
+        let x = match mtext.x_axis {
+            Some(x_axis) => normalize(&x_axis),
+            None => Ocs::new(&normal)
+                .direction_to_wcs(mtext.rotation_radian.unwrap_or(0.0).to_degrees()),
+        };
+        let y = normalize(&cross(&normalize(&normal), &x));
+        let text = mtext
+            .text
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                MTextNode::Text(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect::<String>();
+        let width = if mtext.character_width > 0.0 {
+            mtext.character_width
+        } else if mtext.rectangle_width > 0.0 {
+            mtext.rectangle_width
+        } else {
+            estimated_width(&text, &font)
+        };
+        let height = if mtext.character_height > 0.0 {
+            mtext.character_height
+        } else {
+            let lines = mtext
+                .text
+                .nodes
+                .iter()
+                .filter(|node| matches!(node, MTextNode::Command(MTextCommand::P)))
+                .count()
+                + 1;
+            let spacing = 5.0 / 3.0 * mtext.line_spacing_factor.unwrap_or(1.0);
+            font.height * (1.0 + (lines - 1) as f64 * spacing)
+        };
+        use AttachmentPoint::*;
+        let xs = match mtext.attachment_point {
+            TopLeft | MiddleLeft | BottomLeft => [0.0, width],
+            TopCenter | MiddleCenter | BottomCenter => [-width / 2.0, width / 2.0],
+            TopRight | MiddleRight | BottomRight => [-width, 0.0],
+        };
+        let ys = match mtext.attachment_point {
+            TopLeft | TopCenter | TopRight => [-height, 0.0],
+            MiddleLeft | MiddleCenter | MiddleRight => [-height / 2.0, height / 2.0],
+            BottomLeft | BottomCenter | BottomRight => [0.0, height],
+        };
+        text_box(&mtext.point, &x, &y, xs, ys)
+    }
+
+    /// Bounding box of an entity in WCS (paper space coordinates for paper space entities).
+    ///
+    /// Curves are bounded exactly; texts are approximated.
+    /// INSERTs are exploded and DIMENSIONs are bounded by their blocks (or computed graphics).
+    /// RAY, XLINE and ACIS entities are ignored and give an empty box.
+    pub fn entity_bounding_box(&self, entity: &Entity) -> BoundingBox {
+        match entity {
+            Entity::Insert(insert) => self.insert_bounding_box(insert),
+            Entity::Text(text) => self.text_bounding_box(text),
+            Entity::MText(mtext) => self.mtext_bounding_box(mtext),
+            Entity::Dimension(dim) => {
+                if let Some(block) = self.block(&dim.block_name) {
+                    self.block_bounding_box(block)
+                } else if let Some(style) = self.effective_dim_style(dim) {
+                    let graphics = dim.graphics(&style);
+                    let ocs = dim.ocs();
+                    let mut bbox = BoundingBox::EMPTY;
+                    for p in graphics
+                        .lines
+                        .iter()
+                        .flatten()
+                        .chain(graphics.arrowheads.iter().flatten())
+                    {
+                        bbox.add_point(&ocs.to_wcs(p));
+                    }
+                    for arc in &graphics.arcs {
+                        bbox.add_box(&arc.bounding_box());
+                    }
+                    if let Some(text) = &graphics.text {
+                        bbox.add_box(&self.mtext_bounding_box(text));
+                    }
+                    bbox
+                } else {
+                    BoundingBox::EMPTY
+                }
+            }
+            Entity::Point(point) => point.bounding_box(),
+            Entity::Line(line) => line.bounding_box(),
+            Entity::Circle(circle) => circle.bounding_box(),
+            Entity::Arc(arc) => arc.bounding_box(),
+            Entity::Ellipse(ellipse) => ellipse.bounding_box(),
+            Entity::LwPolyline(polyline) => polyline.bounding_box(),
+            Entity::Polyline(polyline) => polyline.bounding_box(),
+            Entity::Spline(spline) => spline.bounding_box(),
+            Entity::Image(image) => image.bounding_box(),
+            Entity::Wipeout(wipeout) => BoundingBox::from_points(&wipeout.wipeout_boundary_wcs()),
+            Entity::Viewport(viewport) => viewport.bounding_box(),
+            Entity::MLine(mline) => match self.mline_style(mline) {
+                Some(style) => BoundingBox::from_points(mline.polylines(style).iter().flatten()),
+                None => BoundingBox::from_points(mline.vertices.iter().map(|v| &v.coord)),
+            },
+            Entity::Mesh(mesh) => mesh.bounding_box(),
+            Entity::Hatch(hatch) => hatch.bounding_box(),
+            Entity::Ray(_)
+            | Entity::XLine(_)
+            | Entity::Region(_)
+            | Entity::Solid3d(_)
+            | Entity::Body(_)
+            | Entity::Surface(_)
+            | Entity::NotSupported(..) => BoundingBox::EMPTY,
+        }
+    }
+
+    /// Bounding box of the entities of a block definition, in block coordinates.
+    pub fn block_bounding_box(&self, block: &BlockNode) -> BoundingBox {
+        let mut bbox = BoundingBox::EMPTY;
+        for node in &block.entities {
+            bbox.add_box(&self.entity_bounding_box(&node.entity));
+        }
+        bbox
+    }
+
+    /// Bounding box of a block reference in WCS, computed from its exploded entities.
+    pub fn insert_bounding_box(&self, insert: &Insert) -> BoundingBox {
+        let mut bbox = BoundingBox::EMPTY;
+        for node in self.explode_insert(insert) {
+            bbox.add_box(&self.entity_bounding_box(&node.entity));
+        }
+        bbox
+    }
+
+    /// Extents of the visible entities of the ENTITIES section in the model space or paper space.
+    pub fn extents(&self, space: Space) -> BoundingBox {
+        let mut bbox = BoundingBox::EMPTY;
+        for node in &self.entities {
+            if node.header.space == space && node.header.is_visible {
+                bbox.add_box(&self.entity_bounding_box(&node.entity));
+            }
+        }
+        bbox
+    }
+
+    /// Recomputes `$EXTMIN`/`$EXTMAX` and `$PEXTMIN`/`$PEXTMAX`, adding them if missing.
+    /// Header variables of an empty space are left unchanged.
+    pub fn update_extents(&mut self) {
+        for (space, min, max) in [
+            (Space::ModelSpace, "$EXTMIN", "$EXTMAX"),
+            (Space::PaperSpace, "$PEXTMIN", "$PEXTMAX"),
+        ] {
+            let bbox = self.extents(space);
+            if !bbox.is_empty() {
+                self.set_header_point(min, &bbox.min);
+                self.set_header_point(max, &bbox.max);
+            }
+        }
+    }
+
+    fn set_header_point(&mut self, name: &str, p: &[f64; 3]) {
+        let atoms = (0..3)
+            .map(|i| Atom {
+                code: 10 + 10 * i as i16,
+                value: p[i].to_string().into(),
+            })
+            .collect::<Vec<_>>();
+        match self.headers.iter_mut().find(|node| node.node_type == name) {
+            Some(node) => node.atoms = atoms.into(),
+            None => self.headers.push(Node {
+                node_type: name.to_owned().into(),
+                atoms: atoms.into(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+#[test]
+fn test_bounding_box() {
+    let close = |a: &[f64; 3], b: &[f64; 3]| (0..3).all(|i| (a[i] - b[i]).abs() < 1e-9);
+
+    let arc = Arc {
+        circle: Circle {
+            center: [1.0, 1.0, 0.0],
+            radius: 2.0,
+            ..Default::default()
+        },
+        start_degree: 45.0,
+        end_degree: 180.0,
+    };
+    let bbox = arc.bounding_box();
+    assert!(close(&bbox.min, &[-1.0, 1.0, 0.0]));
+    assert!(close(&bbox.max, &[1.0 + 2f64.sqrt(), 3.0, 0.0]));
+
+    // semicircle bulging below the chord from (0, 0) to (2, 0)
+    let polyline = LwPolyline {
+        vertices: vec![
+            LwPolylineVertex {
+                coord: [0.0, 0.0],
+                bulge: Some(1.0),
+                ..Default::default()
+            },
+            LwPolylineVertex {
+                coord: [2.0, 0.0],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let bbox = polyline.bounding_box();
+    assert!(close(&bbox.min, &[0.0, -1.0, 0.0]));
+    assert!(close(&bbox.max, &[2.0, 0.0, 0.0]));
+
+    let mut doc = Document {
+        entities: vec![EntityNode {
+            header: EntityHeader {
+                is_visible: true,
+                ..Default::default()
+            },
+            entity: Entity::LwPolyline(polyline),
+        }],
+        ..Default::default()
+    };
+    doc.update_extents();
+    let extmax = doc.header("$EXTMAX").unwrap();
+    assert_eq!(extmax.atoms.find(10), Some("2"));
+}
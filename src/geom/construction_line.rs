@@ -0,0 +1,88 @@
+use crate::{Ray, XLine};
+
+impl Ray {
+    /// Clips the ray to the axis-aligned box `[min, max]`.
+    /// Returns `None` if the ray does not pass through the box or has no direction.
+    pub fn clip(&self, min: &[f64; 3], max: &[f64; 3]) -> Option<[[f64; 3]; 2]> {
+        clip_line(&self.base_point, &self.unit_direction, 0.0, min, max)
+    }
+}
+
+impl XLine {
+    /// Clips the infinite line to the axis-aligned box `[min, max]`.
+    /// Returns `None` if the line does not pass through the box or has no direction.
+    pub fn clip(&self, min: &[f64; 3], max: &[f64; 3]) -> Option<[[f64; 3]; 2]> {
+        clip_line(
+            &self.base_point,
+            &self.unit_direction,
+            f64::NEG_INFINITY,
+            min,
+            max,
+        )
+    }
+}
+
+// slab method
+fn clip_line(
+    p: &[f64; 3],
+    v: &[f64; 3],
+    t_start: f64,
+    min: &[f64; 3],
+    max: &[f64; 3],
+) -> Option<[[f64; 3]; 2]> {
+    if v.iter().all(|x| *x == 0.0) {
+        return None;
+    }
+    let mut t0 = t_start;
+    let mut t1 = f64::INFINITY;
+    for i in 0..3 {
+        if v[i] == 0.0 {
+            if p[i] < min[i] || max[i] < p[i] {
+                return None;
+            }
+        } else {
+            let a = (min[i] - p[i]) / v[i];
+            let b = (max[i] - p[i]) / v[i];
+            t0 = t0.max(a.min(b));
+            t1 = t1.min(a.max(b));
+        }
+    }
+    (t0 <= t1).then(|| {
+        [
+            super::add(p, &super::scale(v, t0)),
+            super::add(p, &super::scale(v, t1)),
+        ]
+    })
+}
+
+#[test]
+fn test_clip() {
+    let min = [0.0, 0.0, 0.0];
+    let max = [10.0, 10.0, 0.0];
+    let xline = XLine {
+        base_point: [5.0, 5.0, 0.0],
+        unit_direction: [1.0, 0.0, 0.0],
+    };
+    assert_eq!(
+        xline.clip(&min, &max),
+        Some([[0.0, 5.0, 0.0], [10.0, 5.0, 0.0]])
+    );
+    let ray = Ray {
+        base_point: [5.0, 5.0, 0.0],
+        unit_direction: [1.0, 0.0, 0.0],
+    };
+    assert_eq!(
+        ray.clip(&min, &max),
+        Some([[5.0, 5.0, 0.0], [10.0, 5.0, 0.0]])
+    );
+    let ray = Ray {
+        base_point: [5.0, 20.0, 0.0],
+        unit_direction: [1.0, 0.0, 0.0],
+    };
+    assert_eq!(ray.clip(&min, &max), None);
+    let ray = Ray {
+        base_point: [5.0, 5.0, 0.0],
+        unit_direction: [0.0, 0.0, 0.0],
+    };
+    assert_eq!(ray.clip(&min, &max), None);
+}
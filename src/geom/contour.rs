@@ -0,0 +1,477 @@
+use super::{Ocs, PolylineSegment};
+use crate::*;
+
+/// A connected sequence of line and arc segments in the WCS XY plane.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Contour {
+    pub segments: Vec<PolylineSegment>,
+    pub handles: Vec<u32>, // source entity of each segment
+    pub is_closed: bool,
+    pub depth: usize, // number of enclosing closed contours (odd for holes), 0 if open
+    pub parent: Option<usize>, // innermost enclosing closed contour
+}
+
+/// The distance between an open end and the nearest other open end.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContourGap {
+    pub contours: [usize; 2],
+    pub points: [[f64; 2]; 2],
+    pub distance: f64,
+}
+
+/// Contours chained from loose curves.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Contours {
+    pub contours: Vec<Contour>,
+    pub gaps: Vec<ContourGap>,
+}
+
+fn distance(a: &[f64; 2], b: &[f64; 2]) -> f64 {
+    (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+fn reversed(segment: &PolylineSegment) -> PolylineSegment {
+    match *segment {
+        PolylineSegment::Line { start, end } => PolylineSegment::Line {
+            start: end,
+            end: start,
+        },
+        PolylineSegment::Arc {
+            start,
+            end,
+            center,
+            radius,
+            start_radian,
+            sweep_radian,
+        } => PolylineSegment::Arc {
+            start: end,
+            end: start,
+            center,
+            radius,
+            start_radian: start_radian + sweep_radian,
+            sweep_radian: -sweep_radian,
+        },
+    }
+}
+
+// an OCS segment projected to the WCS XY plane, if the OCS is parallel to it
+fn segment_to_xy(segment: &PolylineSegment, ocs: &Ocs, z: f64) -> Option<PolylineSegment> {
+    let normal_z = ocs.axes[2][2];
+    if (normal_z.abs() - 1.0).abs() > 1e-9 {
+        return None;
+    }
+    let xy = |p: &[f64; 2]| {
+        let p = ocs.to_wcs(&[p[0], p[1], z]);
+        [p[0], p[1]]
+    };
+    Some(match *segment {
+        PolylineSegment::Line { start, end } => PolylineSegment::Line {
+            start: xy(&start),
+            end: xy(&end),
+        },
+        PolylineSegment::Arc {
+            start,
+            end,
+            center,
+            radius,
+            sweep_radian,
+            ..
+        } => {
+            let (start, end, center) = (xy(&start), xy(&end), xy(&center));
+            PolylineSegment::Arc {
+                start,
+                end,
+                center,
+                radius,
+                start_radian: (start[1] - center[1]).atan2(start[0] - center[0]),
+                sweep_radian: sweep_radian * normal_z.signum(),
+            }
+        }
+    })
+}
+
+fn arc_segment(
+    center: &[f64; 3],
+    radius: f64,
+    start_degree: f64,
+    sweep_degree: f64,
+) -> PolylineSegment {
+    let point = |degree: f64| {
+        let (sin, cos) = degree.to_radians().sin_cos();
+        [center[0] + radius * cos, center[1] + radius * sin]
+    };
+    PolylineSegment::Arc {
+        start: point(start_degree),
+        end: point(start_degree + sweep_degree),
+        center: [center[0], center[1]],
+        radius,
+        start_radian: start_degree.to_radians(),
+        sweep_radian: sweep_degree.to_radians(),
+    }
+}
+
+// segments of an entity in the WCS XY plane; circles are split into two halves
+fn entity_segments(entity: &Entity) -> Option<Vec<PolylineSegment>> {
+    let (segments, ocs, z) = match entity {
+        Entity::Line(line) => {
+            let segment = PolylineSegment::Line {
+                start: [line.p1[0], line.p1[1]],
+                end: [line.p2[0], line.p2[1]],
+            };
+            return Some(vec![segment]);
+        }
+        Entity::Circle(circle) => (
+            vec![
+                arc_segment(&circle.center, circle.radius, 0.0, 180.0),
+                arc_segment(&circle.center, circle.radius, 180.0, 180.0),
+            ],
+            circle.ocs(),
+            circle.center[2],
+        ),
+        Entity::Arc(arc) => {
+            let sweep = (arc.end_degree - arc.start_degree).rem_euclid(360.0);
+            let circle = &arc.circle;
+            let segment = arc_segment(&circle.center, circle.radius, arc.start_degree, sweep);
+            (vec![segment], circle.ocs(), circle.center[2])
+        }
+        Entity::LwPolyline(polyline) => (
+            polyline.segments(),
+            polyline.ocs(),
+            polyline.elevation.unwrap_or(0.0),
+        ),
+        _ => return None,
+    };
+    segments
+        .iter()
+        .map(|segment| segment_to_xy(segment, &ocs, z))
+        .collect()
+}
+
+impl Contour {
+    pub fn start(&self) -> Option<[f64; 2]> {
+        self.segments.first().map(PolylineSegment::start)
+    }
+
+    pub fn end(&self) -> Option<[f64; 2]> {
+        self.segments.last().map(PolylineSegment::end)
+    }
+
+    /// Reverses the direction of the contour.
+    pub fn reverse(&mut self) {
+        self.segments = self.segments.iter().rev().map(reversed).collect();
+        self.handles.reverse();
+    }
+
+    /// The contour as an LWPOLYLINE in WCS with bulged arcs.
+    /// Gaps within the tolerance are closed by moving each segment's end to the next start.
+    pub fn to_lw_polyline(&self) -> LwPolyline {
+        let vertex = |coord: [f64; 2], bulge: Option<f64>| LwPolylineVertex {
+            coord,
+            bulge,
+            ..Default::default()
+        };
+        let mut vertices = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let bulge = match *segment {
+                    PolylineSegment::Line { .. } => None,
+                    PolylineSegment::Arc { sweep_radian, .. } => Some((sweep_radian / 4.0).tan()),
+                };
+                vertex(segment.start(), bulge)
+            })
+            .collect::<Vec<_>>();
+        if let (false, Some(end)) = (self.is_closed, self.end()) {
+            vertices.push(vertex(end, None));
+        }
+        LwPolyline {
+            vertices,
+            is_closed: self.is_closed,
+            ..Default::default()
+        }
+    }
+}
+
+impl Contours {
+    /// Chains the LINE, ARC, CIRCLE and LWPOLYLINE entities lying in planes parallel
+    /// to the WCS XY plane into contours, joining ends closer than `tolerance`.
+    /// Other entities are ignored.
+    ///
+    /// Where more than two ends meet, the nearest one is taken and the others start new contours.
+    /// Closed contours are nested by containment and oriented counterclockwise
+    /// for even depths (outer boundaries) and clockwise for odd depths (holes).
+    /// Each open end is reported with the nearest other open end as a gap.
+    pub fn chain<'a>(nodes: impl IntoIterator<Item = &'a EntityNode>, tolerance: f64) -> Self {
+        let mut pieces = vec![];
+        for node in nodes {
+            match entity_segments(&node.entity) {
+                Some(segments) => pieces.extend(
+                    segments
+                        .into_iter()
+                        .filter(|segment| segment.length() > 0.0)
+                        .map(|segment| (segment, node.header.handle)),
+                ),
+                None if matches!(
+                    node.entity,
+                    Entity::Circle(_) | Entity::Arc(_) | Entity::LwPolyline(_)
+                ) =>
+                {
+                    log::warn!(
+                        "entity {:X} not parallel to the XY plane: skipped",
+                        node.header.handle
+                    )
+                }
+                None => {}
+            }
+        }
+        let mut contours = chain_pieces(pieces, tolerance);
+        nest(&mut contours);
+        let gaps = find_gaps(&contours);
+        Contours { contours, gaps }
+    }
+
+    pub fn closed(&self) -> impl Iterator<Item = &Contour> {
+        self.contours.iter().filter(|contour| contour.is_closed)
+    }
+
+    pub fn open(&self) -> impl Iterator<Item = &Contour> {
+        self.contours.iter().filter(|contour| !contour.is_closed)
+    }
+
+    /// Start and end points of the open contours.
+    pub fn open_ends(&self) -> Vec<[f64; 2]> {
+        self.open()
+            .flat_map(|contour| contour.start().into_iter().chain(contour.end()))
+            .collect()
+    }
+}
+
+// greedy chaining, extending each contour at both ends by the nearest free piece
+fn chain_pieces(pieces: Vec<(PolylineSegment, u32)>, tolerance: f64) -> Vec<Contour> {
+    let mut is_used = vec![false; pieces.len()];
+    // nearest free piece with an end within the tolerance, and whether that end is its start
+    let nearest = |p: &[f64; 2], is_used: &[bool]| {
+        pieces
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !is_used[*i])
+            .flat_map(|(i, (segment, _))| {
+                [
+                    (i, true, distance(p, &segment.start())),
+                    (i, false, distance(p, &segment.end())),
+                ]
+            })
+            .filter(|(_, _, d)| *d <= tolerance)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+    };
+    let mut contours = vec![];
+    for i in 0..pieces.len() {
+        if is_used[i] {
+            continue;
+        }
+        is_used[i] = true;
+        let mut contour = Contour {
+            segments: vec![pieces[i].0],
+            handles: vec![pieces[i].1],
+            is_closed: false,
+            depth: 0,
+            parent: None,
+        };
+        for is_forward in [true, false] {
+            if !is_forward {
+                contour.reverse();
+            }
+            loop {
+                let (start, end) = (contour.start().unwrap(), contour.end().unwrap());
+                let next = nearest(&end, &is_used);
+                let closing = distance(&start, &end);
+                if closing <= tolerance && next.is_none_or(|(_, _, d)| closing <= d) {
+                    // a single segment closes on itself only if it is curved
+                    contour.is_closed = contour.segments.len() > 1
+                        || matches!(contour.segments[0], PolylineSegment::Arc { .. });
+                    break;
+                }
+                match next {
+                    Some((j, is_start, _)) => {
+                        is_used[j] = true;
+                        let (segment, handle) = &pieces[j];
+                        contour.segments.push(if is_start {
+                            *segment
+                        } else {
+                            reversed(segment)
+                        });
+                        contour.handles.push(*handle);
+                    }
+                    None => break,
+                }
+            }
+            if contour.is_closed {
+                break;
+            }
+        }
+        contours.push(contour);
+    }
+    contours
+}
+
+// sets depths and parents of the closed contours and orients them
+fn nest(contours: &mut [Contour]) {
+    let polylines = contours
+        .iter()
+        .map(|contour| contour.is_closed.then(|| contour.to_lw_polyline()))
+        .collect::<Vec<_>>();
+    let containers = contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            if !contour.is_closed {
+                return vec![];
+            }
+            let segment = &contour.segments[0];
+            let sample = segment.point_at(segment.length() / 2.0);
+            polylines
+                .iter()
+                .enumerate()
+                .filter(|(j, polyline)| {
+                    *j != i && polyline.as_ref().is_some_and(|p| p.contains(&sample))
+                })
+                .map(|(j, _)| j)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for (i, contour) in contours.iter_mut().enumerate() {
+        contour.depth = containers[i].len();
+        contour.parent = containers[i]
+            .iter()
+            .copied()
+            .max_by_key(|&j| containers[j].len());
+        if let Some(polyline) = &polylines[i] {
+            if polyline.is_counterclockwise() != (contour.depth % 2 == 0) {
+                contour.reverse();
+            }
+        }
+    }
+}
+
+fn find_gaps(contours: &[Contour]) -> Vec<ContourGap> {
+    let ends = contours
+        .iter()
+        .enumerate()
+        .filter(|(_, contour)| !contour.is_closed)
+        .flat_map(|(i, contour)| {
+            contour
+                .start()
+                .into_iter()
+                .chain(contour.end())
+                .enumerate()
+                .map(move |(k, p)| (2 * i + k, i, p))
+        })
+        .collect::<Vec<_>>();
+    let mut gaps: Vec<ContourGap> = vec![];
+    let mut pairs = vec![];
+    for (id, i, p) in &ends {
+        let nearest = ends
+            .iter()
+            .filter(|(other, _, _)| other != id)
+            .min_by(|a, b| distance(p, &a.2).total_cmp(&distance(p, &b.2)));
+        if let Some((other, j, q)) = nearest {
+            let pair = (*id.min(other), *id.max(other));
+            if !pairs.contains(&pair) {
+                pairs.push(pair);
+                gaps.push(ContourGap {
+                    contours: [*i, *j],
+                    points: [*p, *q],
+                    distance: distance(p, q),
+                });
+            }
+        }
+    }
+    gaps
+}
+
+#[test]
+fn test_contours_chain() {
+    use std::f64::consts::PI;
+    let node = |handle, entity| EntityNode {
+        header: EntityHeader {
+            handle,
+            ..Default::default()
+        },
+        entity,
+    };
+    let line = |p1: [f64; 2], p2: [f64; 2]| {
+        Entity::Line(Line {
+            p1: [p1[0], p1[1], 0.0],
+            p2: [p2[0], p2[1], 0.0],
+            ..Default::default()
+        })
+    };
+    let circle = Circle {
+        center: [5.0, 5.0, 0.0],
+        radius: 1.0,
+        ..Default::default()
+    };
+    let nodes = vec![
+        // a 10x10 square with rounded top right corner, drawn in mixed directions
+        node(1, line([0.0, 0.0], [10.0, 0.0])),
+        node(2, line([0.0, 10.0], [8.0, 10.0])),
+        node(3, line([10.0, 0.0], [10.0, 8.0])),
+        node(
+            4,
+            Entity::Arc(Arc {
+                circle: Circle {
+                    center: [8.0, 8.0, 0.0],
+                    radius: 2.0,
+                    ..Default::default()
+                },
+                start_degree: 0.0,
+                end_degree: 90.0,
+            }),
+        ),
+        node(5, line([0.0, 0.0], [0.0, 10.0 - 1e-4])),
+        // a hole as a circle drawn clockwise
+        node(
+            6,
+            Entity::Circle(Circle {
+                extrusion_direction: Some([0.0, 0.0, -1.0]),
+                center: [-5.0, 5.0, 0.0],
+                ..circle
+            }),
+        ),
+        // an open path with a gap
+        node(7, line([20.0, 0.0], [25.0, 0.0])),
+        node(8, line([25.5, 0.0], [30.0, 0.0])),
+    ];
+    let result = Contours::chain(&nodes, 1e-3);
+    assert_eq!(result.closed().count(), 2);
+    assert_eq!(result.open().count(), 2);
+
+    let outer = &result.contours[0];
+    assert_eq!(outer.depth, 0);
+    assert_eq!(outer.segments.len(), 5);
+    let polyline = outer.to_lw_polyline();
+    assert!(polyline.is_closed && polyline.is_counterclockwise());
+    assert_eq!(polyline.vertices.len(), 5);
+    assert_eq!(
+        polyline
+            .vertices
+            .iter()
+            .filter(|v| v.bulge.is_some_and(|b| (b - (PI / 8.0).tan()).abs() < 1e-9))
+            .count(),
+        1
+    );
+    assert!((polyline.signed_area() - (100.0 - 4.0 + PI)).abs() < 1e-3);
+
+    let hole = &result.contours[1];
+    assert_eq!(hole.handles, vec![6, 6]);
+    assert_eq!((hole.depth, hole.parent), (1, Some(0)));
+    assert!(!hole.to_lw_polyline().is_counterclockwise());
+
+    assert_eq!(result.open_ends().len(), 4);
+    let gap = result
+        .gaps
+        .iter()
+        .find(|gap| (gap.distance - 0.5).abs() < 1e-9)
+        .unwrap();
+    assert_eq!(gap.contours, [2, 3]);
+}
@@ -0,0 +1,651 @@
+use super::{add, dot, norm, scale, sub};
+use crate::*;
+use std::f64::consts::PI;
+
+/// Graphics of a dimension, equivalent to the content of its anonymous block.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct DimensionGraphics {
+    pub lines: Vec<[[f64; 3]; 2]>, // extension lines, dimension lines, ticks and center marks
+    pub arcs: Vec<Arc>,            // dimension line arcs of angular dimensions
+    pub arrowheads: Vec<[[f64; 3]; 3]>, // filled triangles
+    pub text: Option<MText>,
+}
+
+impl Dimension {
+    /// The measured value computed from the definition points:
+    /// a length, or an angle in degrees for angular dimensions.
+    pub fn measurement(&self) -> f64 {
+        let [p10, p13, p14, p15] = self.definition_points_ocs();
+        match self.dimension_type {
+            DimensionType::RotatedOrHorizontalOrVertical => {
+                dot(&sub(&p14, &p13), &self.linear_direction()).abs()
+            }
+            DimensionType::Aligned => distance(&p13, &p14),
+            DimensionType::Angular | DimensionType::Angular3Point => self
+                .angular_arc()
+                .map_or(0.0, |(_, _, start, end)| (end - start).to_degrees()),
+            DimensionType::Diameter | DimensionType::Radius => distance(&p10, &p15),
+            DimensionType::Ordinate(OrdinateType::X) => (p13[0] - p10[0]).abs(),
+            DimensionType::Ordinate(OrdinateType::Y) => (p13[1] - p10[1]).abs(),
+        }
+    }
+
+    /// The dimension text as displayed: the formatted measurement, or the text override (code 1)
+    /// in which "<>" is replaced by the measurement. `None` if the text is suppressed by " ".
+    pub fn display_text(&self, style: &DimStyle) -> Option<String> {
+        let measurement = match self.dimension_type {
+            DimensionType::Angular | DimensionType::Angular3Point => {
+                style.format_angle(self.measurement())
+            }
+            DimensionType::Radius => format!("R{}", style.format_length(self.measurement())),
+            DimensionType::Diameter => format!("%%c{}", style.format_length(self.measurement())),
+            _ => style.format_length(self.measurement()),
+        };
+        match self.text.as_deref() {
+            None | Some("") => Some(measurement),
+            Some(" ") => None,
+            Some(text) => Some(text.replace("<>", &measurement)),
+        }
+    }
+
+    /// Generates the graphics of the dimension without its anonymous block.
+    /// The definition points are converted from WCS to the OCS, so the graphics lie
+    /// in the XY plane of the OCS like the text middle point.
+    /// Ordinate dimensions are drawn as the leader line and the text only.
+    pub fn graphics(&self, style: &DimStyle) -> DimensionGraphics {
+        let mut builder = GraphicsBuilder {
+            style,
+            scale: if style.scale_factor > 0.0 {
+                style.scale_factor
+            } else {
+                1.0
+            },
+            target: DimensionGraphics::default(),
+        };
+        let [p10, p13, p14, p15] = self.definition_points_ocs();
+        let mut text_direction = [1.0, 0.0, 0.0];
+        match self.dimension_type {
+            DimensionType::RotatedOrHorizontalOrVertical | DimensionType::Aligned => {
+                let d = self.linear_direction();
+                let q1 = add(&p10, &scale(&d, dot(&sub(&p13, &p10), &d)));
+                let q2 = add(&p10, &scale(&d, dot(&sub(&p14, &p10), &d)));
+                if !style.extension_line1_suppressed {
+                    builder.extension_line(&p13, &q1);
+                }
+                if !style.extension_line2_suppressed {
+                    builder.extension_line(&p14, &q2);
+                }
+                builder.dimension_line(&q1, &q2);
+                text_direction = d;
+            }
+            DimensionType::Angular | DimensionType::Angular3Point => {
+                if let Some((center, radius, start, end)) = self.angular_arc() {
+                    let lines = if self.dimension_type == DimensionType::Angular {
+                        [vec![p13, p14], vec![p15, p10]]
+                    } else {
+                        [vec![p13], vec![p14]]
+                    };
+                    for angle in [start, end] {
+                        let points = nearest_to_ray(&center, angle, &lines);
+                        builder.angular_extension_line(&center, radius, angle, points);
+                    }
+                    builder.dimension_arc(&center, radius, start, end);
+                }
+            }
+            DimensionType::Diameter => {
+                let text_point = &self.text_mid_point;
+                let center = scale(&add(&p10, &p15), 0.5);
+                let radius = distance(&p10, &p15) / 2.0;
+                if distance(text_point, &center) > radius {
+                    // the text is outside, so the line is extended to the text
+                    let (near, far) = if distance(text_point, &p10) < distance(text_point, &p15) {
+                        (p10, p15)
+                    } else {
+                        (p15, p10)
+                    };
+                    builder.target.lines.push([*text_point, far]);
+                    builder.arrowhead(&near, &sub(&near, text_point));
+                    builder.arrowhead(&far, &sub(&near, &far));
+                } else {
+                    builder.target.lines.push([p10, p15]);
+                    builder.arrowhead(&p10, &sub(&p15, &p10));
+                    builder.arrowhead(&p15, &sub(&p10, &p15));
+                }
+                builder.center_mark(&center);
+            }
+            DimensionType::Radius => {
+                let text_point = &self.text_mid_point;
+                let radius = distance(&p10, &p15);
+                if distance(text_point, &p10) > radius {
+                    builder.target.lines.push([*text_point, p15]);
+                    builder.arrowhead(&p15, &sub(text_point, &p15));
+                } else {
+                    builder.target.lines.push([p10, p15]);
+                    builder.arrowhead(&p15, &sub(&p10, &p15));
+                }
+                builder.center_mark(&p10);
+            }
+            DimensionType::Ordinate(ordinate_type) => {
+                let v = sub(&p14, &p13);
+                let length = norm(&v);
+                if length > 0.0 {
+                    let u = scale(&v, 1.0 / length);
+                    let start = add(&p13, &scale(&u, builder.extension_line_offset()));
+                    builder.target.lines.push([start, p14]);
+                }
+                if ordinate_type == OrdinateType::X {
+                    text_direction = [0.0, 1.0, 0.0];
+                }
+            }
+        }
+        for arc in &mut builder.target.arcs {
+            arc.circle.extrusion_direction = self.extrusion_direction;
+        }
+        let text = self.display_text(style);
+        builder.target.text = text.map(|text| self.text_entity(&text, &builder, &text_direction));
+        builder.target
+    }
+
+    fn text_entity(&self, text: &str, builder: &GraphicsBuilder, direction: &[f64; 3]) -> MText {
+        let mut rotation = match self.text_rotation_angle {
+            Some(degree) => degree.to_radians(),
+            None => angle_of(direction),
+        };
+        // keep the text readable
+        if rotation > PI / 2.0 + 1e-9 && rotation <= 3.0 * PI / 2.0 + 1e-9 {
+            rotation -= PI;
+        }
+        let mut mtext = MText {
+            text: MTextFormatString {
+                raw: text.to_owned(),
+                nodes: Vec::new(),
+            },
+            style_name: None,
+            point: self.text_mid_point,
+            x_axis: Some([rotation.cos(), rotation.sin(), 0.0]),
+            height: builder.style.text_height * builder.scale,
+            rotation_radian: Some(rotation),
+            extrusion_vector: self.extrusion_direction,
+            attachment_point: AttachmentPoint::MiddleCenter,
+            ..Default::default()
+        };
+        mtext.text.parse_and_build_nodes();
+        mtext
+    }
+
+    // 10, 13, 14 and 15 in the OCS
+    fn definition_points_ocs(&self) -> [[f64; 3]; 4] {
+        let ocs = self.ocs();
+        [
+            self.definition_point,
+            self.definition_point2.unwrap_or_default(),
+            self.definition_point3.unwrap_or_default(),
+            self.definition_point4.unwrap_or_default(),
+        ]
+        .map(|p| if ocs.is_wcs() { p } else { ocs.from_wcs(&p) })
+    }
+
+    fn linear_direction(&self) -> [f64; 3] {
+        if self.dimension_type == DimensionType::Aligned {
+            let [_, p13, p14, _] = self.definition_points_ocs();
+            let v = sub(&p14, &p13);
+            let length = norm(&v);
+            if length > 0.0 {
+                return scale(&v, 1.0 / length);
+            }
+        }
+        let theta = self.rotation_angle.unwrap_or(0.0).to_radians();
+        [theta.cos(), theta.sin(), 0.0]
+    }
+
+    // (center, radius, start angle, end angle) of the dimension arc
+    // where the end angle is larger than the start angle
+    fn angular_arc(&self) -> Option<([f64; 3], f64, f64, f64)> {
+        let [p10, p13, p14, p15] = self.definition_points_ocs();
+        if self.dimension_type == DimensionType::Angular3Point {
+            let a1 = angle_of(&sub(&p13, &p15));
+            let a2 = angle_of(&sub(&p14, &p15));
+            let location = angle_of(&sub(&p10, &p15));
+            let radius = distance(&p10, &p15);
+            return Some(if ccw_angle(a1, location) <= ccw_angle(a1, a2) {
+                (p15, radius, a1, a1 + ccw_angle(a1, a2))
+            } else {
+                (p15, radius, a2, a2 + ccw_angle(a2, a1))
+            });
+        }
+        let center = intersection(&p13, &sub(&p14, &p13), &p15, &sub(&p10, &p15))?;
+        let location = self.arc_location.unwrap_or(self.text_mid_point);
+        let radius = distance(&location, &center);
+        let theta = angle_of(&sub(&location, &center));
+        let a1 = angle_of(&sub(&p14, &p13));
+        let a2 = angle_of(&sub(&p10, &p15));
+        // choose the sector containing the arc location among those between the two lines
+        [a1, a1 + PI]
+            .iter()
+            .flat_map(|&r1| [(r1, a2), (r1, a2 + PI), (a2, r1), (a2 + PI, r1)])
+            .map(|(start, end)| (start, ccw_angle(start, end)))
+            .find(|&(start, sweep)| sweep <= PI + 1e-9 && ccw_angle(start, theta) <= sweep)
+            .map(|(start, sweep)| (center, radius, start, start + sweep))
+    }
+}
+
+impl DimStyle {
+    /// Formats a length honoring DIMLFAC, DIMRND, DIMDEC, DIMZIN, DIMDSEP, tolerances
+    /// (DIMTOL, DIMLIM, DIMTDEC, DIMTZIN) and DIMPOST.
+    /// The result is an MTEXT format string.
+    pub fn format_length(&self, length: f64) -> String {
+        let factor = if self.linear_measurement_scale_factor != 0.0 {
+            self.linear_measurement_scale_factor
+        } else {
+            1.0
+        };
+        let value = self.round(length * factor);
+        let format = |x: f64| self.format_number(x, self.decimal_places, &self.zero_suppression);
+        let format_tolerance = |x: f64| {
+            self.format_number(
+                x,
+                self.tolerance_decimal_places,
+                &self.tolerance_zero_suppression,
+            )
+        };
+        let text = if self.dimension_limits != 0 {
+            format!(
+                "\\S{}^{};",
+                format(value + self.plus_tolerance),
+                format(value - self.minus_tolerance)
+            )
+        } else if self.tolerance != 0 {
+            if self.plus_tolerance == self.minus_tolerance {
+                format!(
+                    "{}%%p{}",
+                    format(value),
+                    format_tolerance(self.plus_tolerance)
+                )
+            } else {
+                format!(
+                    "{}\\S+{}^-{};",
+                    format(value),
+                    format_tolerance(self.plus_tolerance),
+                    format_tolerance(self.minus_tolerance)
+                )
+            }
+        } else {
+            format(value)
+        };
+        apply_suffix(&self.general_dimensioning_suffix, &text)
+    }
+
+    /// Formats an angle given in degrees honoring DIMADEC and DIMAZIN.
+    pub fn format_angle(&self, degree: f64) -> String {
+        let decimal_places = if self.angular_decimal_places < 0 {
+            self.decimal_places
+        } else {
+            self.angular_decimal_places
+        };
+        format!(
+            "{}%%d",
+            self.format_number(degree, decimal_places, &self.angular_zero_suppression)
+        )
+    }
+
+    fn format_number(&self, value: f64, decimal_places: i16, zin: &ZeroSuppression) -> String {
+        let mut s = format_decimal(value, decimal_places);
+        if zin.trailing_zeros && s.contains('.') {
+            s.truncate(s.trim_end_matches('0').trim_end_matches('.').len());
+        }
+        if zin.leading_zeros {
+            if let Some(rest) = s.strip_prefix("0.") {
+                s = format!(".{}", rest);
+            } else if let Some(rest) = s.strip_prefix("-0.") {
+                s = format!("-.{}", rest);
+            }
+        }
+        match self.decimal_separator {
+            Some(sep) if sep != '.' => s.replace('.', &sep.to_string()),
+            _ => s,
+        }
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        if self.rounding_value > 0.0 {
+            (value / self.rounding_value).round() * self.rounding_value
+        } else {
+            value
+        }
+    }
+}
+
+impl crate::Document {
+    /// Returns the dimension style of `dim` with its DSTYLE overrides applied.
+    pub fn effective_dim_style(&self, dim: &Dimension) -> Option<DimStyle> {
+        let mut style = self.dim_style(&dim.dimension_style)?.clone();
+        style.apply_overrides(&dim.style_overrides);
+        Some(style)
+    }
+
+    pub fn dim_style(&self, name: &str) -> Option<&DimStyle> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find_map(|entry| match &entry.record {
+                TableRecord::DimStyle(style) if entry.name.eq_ignore_ascii_case(name) => {
+                    Some(style.as_ref())
+                }
+                _ => None,
+            })
+    }
+}
+
+// DIMPOST: "<>" is replaced by the measurement, otherwise the suffix is appended
+fn apply_suffix(suffix: &str, text: &str) -> String {
+    if suffix.contains("<>") {
+        suffix.replacen("<>", text, 1)
+    } else {
+        format!("{}{}", text, suffix)
+    }
+}
+
+fn format_decimal(value: f64, decimal_places: i16) -> String {
+    let s = format!("{:.*}", decimal_places.max(0) as usize, value);
+    // avoid "-0.00"
+    if s.starts_with('-') && s[1..].chars().all(|c| c == '0' || c == '.') {
+        s[1..].to_owned()
+    } else {
+        s
+    }
+}
+
+struct GraphicsBuilder<'a> {
+    style: &'a DimStyle,
+    scale: f64,
+    target: DimensionGraphics,
+}
+
+impl<'a> GraphicsBuilder<'a> {
+    fn extension_line_offset(&self) -> f64 {
+        self.style.extension_line_offset * self.scale
+    }
+
+    // an extension line from the origin p to the dimension line at q
+    fn extension_line(&mut self, p: &[f64; 3], q: &[f64; 3]) {
+        let v = sub(q, p);
+        let length = norm(&v);
+        if length > 0.0 {
+            let u = scale(&v, 1.0 / length);
+            let start = add(p, &scale(&u, self.extension_line_offset()));
+            let end = add(
+                q,
+                &scale(&u, self.style.extension_line_extension * self.scale),
+            );
+            self.target.lines.push([start, end]);
+        }
+    }
+
+    fn angular_extension_line(
+        &mut self,
+        center: &[f64; 3],
+        radius: f64,
+        angle: f64,
+        points: &[[f64; 3]],
+    ) {
+        let u = [angle.cos(), angle.sin(), 0.0];
+        let ts = points.iter().map(|p| dot(&sub(p, center), &u));
+        let t_max = ts.clone().fold(f64::NEG_INFINITY, f64::max);
+        let t_min = ts.fold(f64::INFINITY, f64::min);
+        let offset = self.extension_line_offset();
+        let extension = self.style.extension_line_extension * self.scale;
+        let at = |t: f64| add(center, &scale(&u, t));
+        if radius > t_max {
+            self.target
+                .lines
+                .push([at(t_max + offset), at(radius + extension)]);
+        } else if radius < t_min {
+            self.target
+                .lines
+                .push([at(t_min - offset), at(radius - extension)]);
+        }
+    }
+
+    fn is_tick(&self) -> bool {
+        self.style.tick_size > 0.0
+    }
+
+    fn arrow_size(&self) -> f64 {
+        self.style.arrow_size * self.scale
+    }
+
+    fn dimension_line(&mut self, q1: &[f64; 3], q2: &[f64; 3]) {
+        let v = sub(q2, q1);
+        let length = norm(&v);
+        if length == 0.0 {
+            return;
+        }
+        let u = scale(&v, 1.0 / length);
+        if self.is_tick() {
+            let extension = scale(&u, self.style.dimension_line_extension * self.scale);
+            self.target
+                .lines
+                .push([sub(q1, &extension), add(q2, &extension)]);
+            self.tick(q1, &u);
+            self.tick(q2, &u);
+        } else if length >= 2.0 * self.arrow_size() {
+            self.target.lines.push([*q1, *q2]);
+            self.arrowhead(q1, &u);
+            self.arrowhead(q2, &scale(&u, -1.0));
+        } else {
+            // arrowheads do not fit between the extension lines
+            let outside = scale(&u, 2.0 * self.arrow_size());
+            self.target
+                .lines
+                .push([sub(q1, &outside), add(q2, &outside)]);
+            self.arrowhead(q1, &scale(&u, -1.0));
+            self.arrowhead(q2, &u);
+        }
+    }
+
+    fn dimension_arc(&mut self, center: &[f64; 3], radius: f64, start: f64, end: f64) {
+        self.target.arcs.push(Arc {
+            circle: Circle {
+                center: *center,
+                radius,
+                ..Default::default()
+            },
+            start_degree: start.to_degrees(),
+            end_degree: end.to_degrees(),
+        });
+        let at = |angle: f64| add(center, &[radius * angle.cos(), radius * angle.sin(), 0.0]);
+        let tangent = |angle: f64| [-angle.sin(), angle.cos(), 0.0];
+        if self.is_tick() {
+            self.tick(&at(start), &tangent(start));
+            self.tick(&at(end), &tangent(end));
+        } else {
+            self.arrowhead(&at(start), &tangent(start));
+            self.arrowhead(&at(end), &scale(&tangent(end), -1.0));
+        }
+    }
+
+    // a filled arrowhead whose tip is at p and whose body lies in the direction v
+    fn arrowhead(&mut self, tip: &[f64; 3], v: &[f64; 3]) {
+        let length = norm(v);
+        let size = self.arrow_size();
+        if length == 0.0 || size <= 0.0 {
+            return;
+        }
+        let u = scale(v, size / length);
+        let n = [-u[1] / 6.0, u[0] / 6.0, 0.0];
+        let base = add(tip, &u);
+        self.target
+            .arrowheads
+            .push([*tip, add(&base, &n), sub(&base, &n)]);
+    }
+
+    // an oblique stroke at p on the dimension line in the direction u
+    fn tick(&mut self, p: &[f64; 3], u: &[f64; 3]) {
+        let size = self.style.tick_size * self.scale / 2.0;
+        let w = [(u[0] - u[1]) * size, (u[0] + u[1]) * size, 0.0];
+        self.target.lines.push([sub(p, &w), add(p, &w)]);
+    }
+
+    fn center_mark(&mut self, center: &[f64; 3]) {
+        let size = self.style.center_mark_size.abs() * self.scale;
+        if size > 0.0 {
+            let [x, y, z] = *center;
+            self.target.lines.push([[x - size, y, z], [x + size, y, z]]);
+            self.target.lines.push([[x, y - size, z], [x, y + size, z]]);
+        }
+    }
+}
+
+// the set of points lying on the ray from the center in the direction of the angle
+fn nearest_to_ray<'a>(
+    center: &[f64; 3],
+    angle: f64,
+    candidates: &'a [Vec<[f64; 3]>],
+) -> &'a [[f64; 3]] {
+    let u = [angle.cos(), angle.sin(), 0.0];
+    let deviation = |points: &Vec<[f64; 3]>| {
+        points
+            .iter()
+            .map(|p| {
+                let v = sub(p, center);
+                (u[0] * v[1] - u[1] * v[0]).abs() / norm(&v).max(f64::MIN_POSITIVE)
+            })
+            .sum::<f64>()
+    };
+    candidates
+        .iter()
+        .min_by(|a, b| deviation(a).total_cmp(&deviation(b)))
+        .map_or(&[], |points| points.as_slice())
+}
+
+fn distance(p: &[f64; 3], q: &[f64; 3]) -> f64 {
+    norm(&sub(p, q))
+}
+
+fn angle_of(v: &[f64; 3]) -> f64 {
+    let angle = v[1].atan2(v[0]);
+    if angle < 0.0 {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+// counterclockwise angle from `from` to `to` in [0, 2π)
+fn ccw_angle(from: f64, to: f64) -> f64 {
+    (to - from).rem_euclid(2.0 * PI)
+}
+
+// intersection of the lines p + s * u and q + t * v in the XY plane
+fn intersection(p: &[f64; 3], u: &[f64; 3], q: &[f64; 3], v: &[f64; 3]) -> Option<[f64; 3]> {
+    let det = u[0] * v[1] - u[1] * v[0];
+    if det.abs() < 1e-12 {
+        return None;
+    }
+    let w = sub(q, p);
+    let s = (w[0] * v[1] - w[1] * v[0]) / det;
+    Some(add(p, &scale(u, s)))
+}
+
+#[test]
+fn test_linear_graphics() {
+    let style = DimStyle {
+        scale_factor: 1.0,
+        arrow_size: 2.5,
+        extension_line_offset: 1.0,
+        extension_line_extension: 1.5,
+        text_height: 2.5,
+        decimal_places: 1,
+        general_dimensioning_suffix: "<> mm".to_owned(),
+        ..Default::default()
+    };
+    let dim = Dimension {
+        definition_point: [100.0, 20.0, 0.0],
+        definition_point2: Some([0.0, 0.0, 0.0]),
+        definition_point3: Some([100.0, 5.0, 0.0]),
+        text_mid_point: [50.0, 20.0, 0.0],
+        ..Default::default()
+    };
+    assert_eq!(dim.measurement(), 100.0);
+    let graphics = dim.graphics(&style);
+    assert_eq!(
+        graphics.lines,
+        [
+            [[0.0, 1.0, 0.0], [0.0, 21.5, 0.0]],
+            [[100.0, 6.0, 0.0], [100.0, 21.5, 0.0]],
+            [[0.0, 20.0, 0.0], [100.0, 20.0, 0.0]],
+        ]
+    );
+    assert_eq!(graphics.arrowheads.len(), 2);
+    assert_eq!(graphics.arrowheads[0][0], [0.0, 20.0, 0.0]);
+    assert_eq!(graphics.text.unwrap().text.raw, "100.0 mm");
+}
+
+#[test]
+fn test_angular_graphics() {
+    let dim = Dimension {
+        dimension_type: DimensionType::Angular,
+        definition_point2: Some([1.0, 0.0, 0.0]),
+        definition_point3: Some([2.0, 0.0, 0.0]),
+        definition_point4: Some([0.0, 1.0, 0.0]),
+        definition_point: [0.0, 2.0, 0.0],
+        arc_location: Some([3.0, 3.0, 0.0]),
+        ..Default::default()
+    };
+    assert!((dim.measurement() - 90.0).abs() < 1e-9);
+    let graphics = dim.graphics(&DimStyle::default());
+    let arc = &graphics.arcs[0];
+    assert!((arc.circle.radius - 18f64.sqrt()).abs() < 1e-9);
+    assert!(arc.start_degree.abs() < 1e-9 && (arc.end_degree - 90.0).abs() < 1e-9);
+    assert_eq!(graphics.lines.len(), 2);
+    // DIMADEC = -1 by default, so that DIMDEC = 4 applies
+    assert_eq!(graphics.text.unwrap().text.raw, "90.0000°");
+}
+
+#[test]
+fn test_radius_graphics_in_ocs() {
+    // the OCS X axis is the WCS -X axis
+    let dim = Dimension {
+        dimension_type: DimensionType::Radius,
+        definition_point: [0.0, 0.0, 0.0],
+        definition_point4: Some([10.0, 0.0, 0.0]),
+        text_mid_point: [-15.0, 0.0, 0.0],
+        extrusion_direction: Some([0.0, 0.0, -1.0]),
+        ..Default::default()
+    };
+    assert_eq!(dim.measurement(), 10.0);
+    let graphics = dim.graphics(&DimStyle::default());
+    assert_eq!(graphics.lines[0], [[-15.0, 0.0, 0.0], [-10.0, 0.0, 0.0]]);
+}
+
+#[test]
+fn test_format_length() {
+    assert_eq!(DimStyle::default().format_length(1.5), "1.5000");
+    let style = DimStyle {
+        decimal_places: 2,
+        rounding_value: 0.5,
+        tolerance: 1,
+        plus_tolerance: 0.1,
+        minus_tolerance: 0.1,
+        tolerance_decimal_places: 2,
+        ..Default::default()
+    };
+    assert_eq!(style.format_length(12.3), "12.50%%p0.10");
+    let style = DimStyle {
+        plus_tolerance: 0.2,
+        ..style
+    };
+    assert_eq!(style.format_length(12.2), "12.00\\S+0.20^-0.10;");
+    let style = DimStyle {
+        tolerance: 0,
+        zero_suppression: ZeroSuppression {
+            leading_zeros: true,
+            trailing_zeros: true,
+            ..Default::default()
+        },
+        decimal_separator: Some(','),
+        ..style
+    };
+    assert_eq!(style.format_length(0.26), ",5");
+    assert_eq!(style.format_length(12.2), "12");
+}
@@ -0,0 +1,50 @@
+use super::{add, cross, normalize, scale};
+use crate::*;
+
+impl Ellipse {
+    pub fn normal(&self) -> [f64; 3] {
+        normalize(&self.extrusion_direction.unwrap_or([0.0, 0.0, 1.0]))
+    }
+
+    /// The minor axis in WCS, relative to the center.
+    pub fn minor_axis(&self) -> [f64; 3] {
+        scale(&cross(&self.normal(), &self.major_axis), self.axis_ratio)
+    }
+
+    /// WCS point at a parameter, measured counterclockwise about the extrusion direction.
+    pub fn point_at(&self, parameter: f64) -> [f64; 3] {
+        let (sin, cos) = parameter.sin_cos();
+        add(
+            &add(&self.center, &scale(&self.major_axis, cos)),
+            &scale(&self.minor_axis(), sin),
+        )
+    }
+}
+
+impl Circle {
+    /// The circle as a full ELLIPSE entity.
+    pub fn to_ellipse(&self) -> Ellipse {
+        let ocs = self.ocs();
+        Ellipse {
+            center: ocs.to_wcs(&self.center),
+            major_axis: scale(&ocs.axes[0], self.radius),
+            extrusion_direction: self.extrusion_direction,
+            ..Default::default()
+        }
+    }
+}
+
+impl Arc {
+    /// The arc as an elliptical arc with the same start and end points.
+    pub fn to_ellipse(&self) -> Ellipse {
+        let mut end = self.end_degree;
+        while end <= self.start_degree {
+            end += 360.0;
+        }
+        Ellipse {
+            start_parameter: self.start_degree.to_radians(),
+            end_parameter: end.to_radians(),
+            ..self.circle.to_ellipse()
+        }
+    }
+}
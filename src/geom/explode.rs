@@ -0,0 +1,202 @@
+use super::{Matrix, Transform};
+use crate::*;
+
+// nesting limit against blocks that (indirectly) reference themselves
+const MAX_NESTING_DEPTH: usize = 32;
+
+impl Insert {
+    /// Transformations from the coordinates of a block with `base_point` to WCS,
+    /// one for each cell of a MINSERT array (row by row).
+    pub fn block_transforms(&self, base_point: &[f64; 3]) -> Vec<Matrix> {
+        let ocs = self.ocs();
+        let [ax, ay, az] = ocs.axes;
+        let to_wcs = Matrix::from_axes(ax, ay, az, [0.0, 0.0, 0.0]);
+        let base = Matrix::translation([-base_point[0], -base_point[1], -base_point[2]])
+            .then(&Matrix::scaling(self.scale_factor));
+        let placement = Matrix::rotation_z(self.rotation_degree)
+            .then(&Matrix::translation(self.insertion_point))
+            .then(&to_wcs);
+        let mut transforms = vec![];
+        for row in 0..self.row_count.max(1) {
+            for column in 0..self.column_count.max(1) {
+                let cell = Matrix::translation([
+                    column as f64 * self.column_spacing,
+                    row as f64 * self.row_spacing,
+                    0.0,
+                ]);
+                transforms.push(base.then(&cell).then(&placement));
+            }
+        }
+        transforms
+    }
+}
+
+// properties of an entity referenced by an INSERT, as seen from outside the block
+fn inherit_by_block(child: &mut EntityHeader, parent: &EntityHeader) {
+    if child.layer == "0" {
+        child.layer = parent.layer.clone();
+    }
+    if child.color_number == ColorNumber::ByBlock && child.color_rgb.is_none() {
+        child.color_number = parent.color_number;
+        child.color_rgb = parent.color_rgb;
+        child.color_name = parent.color_name.clone();
+    }
+    if child.line_type == LineTypeRef::ByBlock {
+        child.line_type = parent.line_type.clone();
+    }
+    if child.line_weight == Some(-2) {
+        child.line_weight = parent.line_weight;
+    }
+    if child.transparency == Some(0x0100_0000) {
+        child.transparency = parent.transparency;
+    }
+    child.is_visible &= parent.is_visible;
+}
+
+impl Document {
+    /// Finds a block definition by name (case-insensitive).
+    pub fn block(&self, name: &str) -> Option<&BlockNode> {
+        self.blocks
+            .iter()
+            .find(|block| block.block_name.eq_ignore_ascii_case(name))
+    }
+
+    /// World-space copies of the entities referenced by an INSERT, with nested INSERTs
+    /// exploded recursively and MINSERT arrays expanded.
+    /// ByBlock properties and layer "0" of nested blocks are inherited from the nested INSERTs,
+    /// while those referring to `insert` itself are left for the caller to resolve.
+    /// The copies keep the handles of the entities in the block definitions.
+    /// Entities that cannot be transformed, such as ACIS entities, are skipped.
+    pub fn explode_insert(&self, insert: &Insert) -> Vec<EntityNode> {
+        self.explode_insert_nested(insert, 0)
+    }
+
+    fn explode_insert_nested(&self, insert: &Insert, depth: usize) -> Vec<EntityNode> {
+        let block = match self.block(&insert.block_name) {
+            Some(block) if depth < MAX_NESTING_DEPTH => block,
+            Some(_) => {
+                log::warn!("blocks nested too deep: {}", insert.block_name);
+                return vec![];
+            }
+            None => {
+                log::warn!("block not found: {}", insert.block_name);
+                return vec![];
+            }
+        };
+        let transforms = insert.block_transforms(&block.base_point);
+        let mut entities = vec![];
+        for m in &transforms {
+            let mut push = |mut child: EntityNode| {
+                if child.entity.transform(m) {
+                    entities.push(child);
+                } else {
+                    log::warn!("entity not transformable: {:x}", child.header.handle);
+                }
+            };
+            for node in &block.entities {
+                match &node.entity {
+                    Entity::Insert(nested) => {
+                        for mut child in self.explode_insert_nested(nested, depth + 1) {
+                            inherit_by_block(&mut child.header, &node.header);
+                            push(child);
+                        }
+                    }
+                    _ => push(node.clone()),
+                }
+            }
+        }
+        entities
+    }
+
+    /// Entities of the ENTITIES section with every INSERT replaced by its exploded content.
+    pub fn flatten(&self) -> Vec<EntityNode> {
+        let mut entities = vec![];
+        for node in &self.entities {
+            match &node.entity {
+                Entity::Insert(insert) => {
+                    for mut child in self.explode_insert(insert) {
+                        inherit_by_block(&mut child.header, &node.header);
+                        child.header.space = node.header.space;
+                        entities.push(child);
+                    }
+                }
+                _ => entities.push(node.clone()),
+            }
+        }
+        entities
+    }
+}
+
+#[test]
+fn test_explode_insert() {
+    let line = EntityNode {
+        header: EntityHeader {
+            layer: "0".to_owned(),
+            color_number: ColorNumber::ByBlock,
+            is_visible: true,
+            ..Default::default()
+        },
+        entity: Entity::Line(Line {
+            p1: [1.0, 1.0, 0.0],
+            p2: [2.0, 1.0, 0.0],
+            ..Default::default()
+        }),
+    };
+    // left out of the exploded entities since it cannot be transformed
+    let table = EntityNode {
+        header: EntityHeader::default(),
+        entity: Entity::NotSupported("ACAD_TABLE".to_owned(), Vec::new()),
+    };
+    let block = |name: &str, base_point, entities| BlockNode {
+        handle: 0,
+        block_record_handle: 0,
+        layer: "0".to_owned(),
+        block_name: name.to_owned(),
+        block_flags: BlockFlags::default(),
+        base_point,
+        xref_path_name: String::default(),
+        description: String::default(),
+        entities,
+    };
+    let inner = EntityNode {
+        header: EntityHeader {
+            layer: "Inner".to_owned(),
+            color_number: ColorNumber::Number(1),
+            is_visible: true,
+            ..Default::default()
+        },
+        entity: Entity::Insert(Insert {
+            block_name: "LINE".to_owned(),
+            scale_factor: [2.0, 1.0, 1.0],
+            ..Default::default()
+        }),
+    };
+    let doc = Document {
+        blocks: vec![
+            block("LINE", [1.0, 1.0, 0.0], vec![line, table]),
+            block("OUTER", [0.0, 0.0, 0.0], vec![inner]),
+        ],
+        ..Default::default()
+    };
+    let outer = Insert {
+        block_name: "OUTER".to_owned(),
+        insertion_point: [10.0, 0.0, 0.0],
+        rotation_degree: 90.0,
+        column_count: 2,
+        column_spacing: 5.0,
+        ..Default::default()
+    };
+    let entities = doc.explode_insert(&outer);
+    assert_eq!(entities.len(), 2);
+    assert_eq!(entities[0].header.layer, "Inner");
+    assert_eq!(entities[0].header.color_number, ColorNumber::Number(1));
+    let close = |a: &[f64; 3], b: &[f64; 3]| (0..3).all(|i| (a[i] - b[i]).abs() < 1e-9);
+    match (&entities[0].entity, &entities[1].entity) {
+        (Entity::Line(first), Entity::Line(second)) => {
+            assert!(close(&first.p1, &[10.0, 0.0, 0.0]));
+            assert!(close(&first.p2, &[10.0, 2.0, 0.0]));
+            assert!(close(&second.p1, &[10.0, 5.0, 0.0]));
+        }
+        _ => panic!("not lines"),
+    }
+}
@@ -0,0 +1,449 @@
+use super::{spline::BSpline, Resolution, Tessellate};
+use crate::*;
+use std::f64::consts::PI;
+
+// upper limits against degenerate offsets and dash lengths
+const MAX_LINES_PER_PATTERN_LINE: usize = 1 << 16;
+const MAX_PATTERN_REPEATS_PER_INTERVAL: usize = 1 << 16;
+
+fn sub2(a: &[f64; 2], b: &[f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot2(a: &[f64; 2], b: &[f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn is_close2(a: &[f64; 2], b: &[f64; 2]) -> bool {
+    (a[0] - b[0]).abs() <= 1e-9 * (1.0 + a[0].abs())
+        && (a[1] - b[1]).abs() <= 1e-9 * (1.0 + a[1].abs())
+}
+
+// angles of an arc edge in counterclockwise degrees, from the start to the end;
+// clockwise edges store their angles measured clockwise
+fn edge_angles(start: f64, end: f64, is_counterclockwise: bool, count: usize) -> Vec<f64> {
+    let sweep = if end - start >= 360.0 {
+        360.0
+    } else {
+        (end - start).rem_euclid(360.0)
+    };
+    let sign = if is_counterclockwise { 1.0 } else { -1.0 };
+    (0..=count)
+        .map(|i| sign * (start + sweep * i as f64 / count as f64))
+        .collect()
+}
+
+fn edge_sweep_radian(start: f64, end: f64) -> f64 {
+    if end - start >= 360.0 {
+        2.0 * PI
+    } else {
+        (end - start).rem_euclid(360.0).to_radians()
+    }
+}
+
+impl HatchSplineEdge {
+    // Splines without valid control data fall back to their fit points or control polygon.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 2]> {
+        let control_points = self
+            .control_points
+            .iter()
+            .map(|p| [p[0], p[1], 0.0])
+            .collect::<Vec<_>>();
+        let spline = BSpline {
+            degree: self.degree,
+            knots: &self.knots,
+            control_points: &control_points,
+            weights: if self.is_rational { &self.weights } else { &[] },
+        };
+        match spline.tessellate(resolution) {
+            Some(points) => points.iter().map(|p| [p[0], p[1]]).collect(),
+            None if self.control_points.is_empty() => self.fit_points.clone(),
+            None => self.control_points.clone(),
+        }
+    }
+}
+
+impl HatchEdge {
+    /// Points from the start to the end of the edge, in OCS.
+    pub fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 2]> {
+        match self {
+            HatchEdge::Line { start, end } => vec![*start, *end],
+            HatchEdge::CircularArc {
+                center,
+                radius,
+                start_degree,
+                end_degree,
+                is_counterclockwise,
+            } => {
+                let sweep = edge_sweep_radian(*start_degree, *end_degree);
+                let count = resolution.segment_count(*radius, sweep);
+                edge_angles(*start_degree, *end_degree, *is_counterclockwise, count)
+                    .into_iter()
+                    .map(|degree| {
+                        let (sin, cos) = degree.to_radians().sin_cos();
+                        [center[0] + radius * cos, center[1] + radius * sin]
+                    })
+                    .collect()
+            }
+            HatchEdge::EllipticArc {
+                center,
+                major_axis,
+                axis_ratio,
+                start_degree,
+                end_degree,
+                is_counterclockwise,
+            } => {
+                let minor_axis = [-major_axis[1] * axis_ratio, major_axis[0] * axis_ratio];
+                let sweep = edge_sweep_radian(*start_degree, *end_degree);
+                let radius = major_axis[0].hypot(major_axis[1]);
+                let count = resolution.segment_count(radius, sweep);
+                edge_angles(*start_degree, *end_degree, *is_counterclockwise, count)
+                    .into_iter()
+                    .map(|degree| {
+                        let (sin, cos) = degree.to_radians().sin_cos();
+                        [
+                            center[0] + major_axis[0] * cos + minor_axis[0] * sin,
+                            center[1] + major_axis[1] * cos + minor_axis[1] * sin,
+                        ]
+                    })
+                    .collect()
+            }
+            HatchEdge::Spline(spline) => spline.tessellate(resolution),
+        }
+    }
+}
+
+impl HatchBoundaryPath {
+    /// The boundary as a closed polygon in OCS, without repeating the first point.
+    pub fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 2]> {
+        let mut points: Vec<[f64; 2]> = vec![];
+        match &self.boundary {
+            HatchBoundary::Polyline(polyline) => {
+                let polyline = LwPolyline {
+                    is_closed: true,
+                    elevation: None,
+                    extrusion_direction: None,
+                    ..polyline.clone()
+                };
+                points.extend(polyline.tessellate(resolution).iter().map(|p| [p[0], p[1]]));
+            }
+            HatchBoundary::Edges(edges) => {
+                for edge in edges {
+                    let edge_points = edge.tessellate(resolution);
+                    let skip = match (points.last(), edge_points.first()) {
+                        (Some(last), Some(first)) if is_close2(last, first) => 1,
+                        _ => 0,
+                    };
+                    points.extend(edge_points.into_iter().skip(skip));
+                }
+            }
+        }
+        if points.len() > 1 && is_close2(&points[0], &points[points.len() - 1]) {
+            points.pop();
+        }
+        points
+    }
+}
+
+// whether a point is inside a polygon (even-odd rule)
+fn polygon_contains(polygon: &[[f64; 2]], p: &[f64; 2]) -> bool {
+    let mut inside = false;
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        if (a[1] > p[1]) != (b[1] > p[1])
+            && p[0] < a[0] + (p[1] - a[1]) * (b[0] - a[0]) / (b[1] - a[1])
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// a point on the loop tested against the other loops: the midpoint of the first edge
+fn sample_point(polygon: &[[f64; 2]]) -> Option<[f64; 2]> {
+    match polygon {
+        [] => None,
+        [p] => Some(*p),
+        [p, q, ..] => Some([(p[0] + q[0]) / 2.0, (p[1] + q[1]) / 2.0]),
+    }
+}
+
+// number of other loops enclosing each loop, tested at the midpoint of its first edge
+fn nesting_depths(loops: &[Vec<[f64; 2]>]) -> Vec<usize> {
+    loops
+        .iter()
+        .enumerate()
+        .map(|(i, polygon)| {
+            let sample = match sample_point(polygon) {
+                Some(p) => p,
+                None => return 0,
+            };
+            loops
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| *j != i && polygon_contains(other, &sample))
+                .count()
+        })
+        .collect()
+}
+
+impl Hatch {
+    /// Boundary loops in OCS that take part in the fill, by the island detection style:
+    /// every loop for the normal style (islands alternate between filled and empty),
+    /// the outermost loops and their first-level islands for the outer style,
+    /// and only the outermost loops for the ignore style.
+    /// The nesting comes from the geometry, not from the path flags.
+    pub fn boundary_loops(&self, resolution: &Resolution) -> Vec<Vec<[f64; 2]>> {
+        let loops = self
+            .boundary_paths
+            .iter()
+            .map(|path| path.tessellate(resolution))
+            .filter(|polygon| polygon.len() >= 3)
+            .collect::<Vec<_>>();
+        let max_depth = match self.style {
+            HatchStyle::Normal => usize::MAX,
+            HatchStyle::Outer => 1,
+            HatchStyle::Ignore => 0,
+        };
+        nesting_depths(&loops)
+            .into_iter()
+            .zip(loops)
+            .filter(|(depth, _)| *depth <= max_depth)
+            .map(|(_, polygon)| polygon)
+            .collect()
+    }
+
+    fn to_wcs(&self, ocs: &Ocs, p: &[f64; 2]) -> [f64; 3] {
+        ocs.to_wcs(&[p[0], p[1], self.elevation_point[2]])
+    }
+
+    /// Pattern lines clipped to the filled area (even-odd rule over the boundary loops),
+    /// as line segments in WCS; dots are segments of zero length.
+    /// Solid fills give no segments.
+    pub fn pattern_segments(&self, resolution: &Resolution) -> Vec<[[f64; 3]; 2]> {
+        if self.is_solid_fill {
+            return vec![];
+        }
+        let loops = self.boundary_loops(resolution);
+        let ocs = self.ocs();
+        let mut segments = vec![];
+        for line in &self.pattern_lines {
+            let (sin, cos) = line.angle_degree.to_radians().sin_cos();
+            let (direction, normal) = ([cos, sin], [-sin, cos]);
+            let spacing = dot2(&line.offset, &normal);
+            if spacing.abs() <= 1e-12 || loops.is_empty() {
+                continue;
+            }
+            // range of line indices crossing the loops
+            let heights = loops
+                .iter()
+                .flatten()
+                .map(|p| dot2(&sub2(p, &line.base_point), &normal) / spacing);
+            let (min, max) = heights.fold((f64::MAX, f64::MIN), |(min, max), k| {
+                (min.min(k), max.max(k))
+            });
+            let (first, last) = (min.ceil() as i64, max.floor() as i64);
+            if last - first >= MAX_LINES_PER_PATTERN_LINE as i64 {
+                log::warn!(
+                    "hatch pattern line skipped: too many lines ({})",
+                    last - first + 1
+                );
+                continue;
+            }
+            for k in first..=last {
+                let k = k as f64;
+                let origin = [
+                    line.base_point[0] + line.offset[0] * k,
+                    line.base_point[1] + line.offset[1] * k,
+                ];
+                let point = |u: f64| {
+                    self.to_wcs(
+                        &ocs,
+                        &[origin[0] + direction[0] * u, origin[1] + direction[1] * u],
+                    )
+                };
+                for [u0, u1] in scanline_intervals(&loops, &origin, &direction, &normal) {
+                    for [a, b] in dash_intervals(&line.dash_lengths, u0, u1) {
+                        segments.push([point(a), point(b)]);
+                    }
+                }
+            }
+        }
+        segments
+    }
+
+    /// Filled area as polygons with holes in WCS: the outer ring of each polygon comes first,
+    /// followed by its holes. Rings do not repeat their first point.
+    /// Islands filled again inside holes (normal style) are separate polygons.
+    pub fn fill_polygons(&self, resolution: &Resolution) -> Vec<Vec<Vec<[f64; 3]>>> {
+        let loops = self.boundary_loops(resolution);
+        let depths = nesting_depths(&loops);
+        let ocs = self.ocs();
+        let ring = |polygon: &Vec<[f64; 2]>| {
+            polygon
+                .iter()
+                .map(|p| self.to_wcs(&ocs, p))
+                .collect::<Vec<_>>()
+        };
+        // the innermost enclosing loop of each loop
+        let parents = (0..loops.len())
+            .map(|i| {
+                let sample = sample_point(&loops[i])?;
+                (0..loops.len())
+                    .filter(|&j| j != i && polygon_contains(&loops[j], &sample))
+                    .max_by_key(|&j| depths[j])
+            })
+            .collect::<Vec<_>>();
+        (0..loops.len())
+            .filter(|&i| depths[i].is_multiple_of(2))
+            .map(|i| {
+                let mut rings = vec![ring(&loops[i])];
+                for (j, polygon) in loops.iter().enumerate() {
+                    if parents[j] == Some(i) && depths[j] == depths[i] + 1 {
+                        rings.push(ring(polygon));
+                    }
+                }
+                rings
+            })
+            .collect()
+    }
+}
+
+// intervals of a line inside the loops (even-odd rule), in distances from `origin`
+fn scanline_intervals(
+    loops: &[Vec<[f64; 2]>],
+    origin: &[f64; 2],
+    direction: &[f64; 2],
+    normal: &[f64; 2],
+) -> Vec<[f64; 2]> {
+    let mut crossings = vec![];
+    for polygon in loops {
+        for (i, p) in polygon.iter().enumerate() {
+            let q = &polygon[(i + 1) % polygon.len()];
+            let (p, q) = (sub2(p, origin), sub2(q, origin));
+            let (vp, vq) = (dot2(&p, normal), dot2(&q, normal));
+            // half-open on the side of the normal, so vertices on the line are counted once
+            if (vp > 0.0) != (vq > 0.0) {
+                let t = vp / (vp - vq);
+                crossings.push(dot2(&p, direction) + t * dot2(&sub2(&q, &p), direction));
+            }
+        }
+    }
+    crossings.sort_by(|a, b| a.total_cmp(b));
+    crossings
+        .chunks_exact(2)
+        .filter(|pair| pair[0] < pair[1])
+        .map(|pair| [pair[0], pair[1]])
+        .collect()
+}
+
+// visible parts of the interval [u0, u1] of a pattern line starting at 0
+fn dash_intervals(dash_lengths: &[f64], u0: f64, u1: f64) -> Vec<[f64; 2]> {
+    let pattern_length: f64 = dash_lengths.iter().map(|length| length.abs()).sum();
+    if pattern_length <= 0.0 || (u1 - u0) / pattern_length > MAX_PATTERN_REPEATS_PER_INTERVAL as f64
+    {
+        return vec![[u0, u1]];
+    }
+    let mut intervals = vec![];
+    let mut position = (u0 / pattern_length).floor() * pattern_length;
+    while position < u1 {
+        for &length in dash_lengths {
+            if length > 0.0 {
+                let (a, b) = (position.max(u0), (position + length).min(u1));
+                if a < b {
+                    intervals.push([a, b]);
+                }
+            } else if length == 0.0 && u0 <= position && position <= u1 {
+                intervals.push([position, position]);
+            }
+            position += length.abs();
+        }
+    }
+    intervals
+}
+
+#[test]
+fn test_hatch_expansion() {
+    let square = |x0: f64, y0: f64, size: f64| HatchBoundaryPath {
+        boundary: HatchBoundary::Edges(vec![
+            HatchEdge::Line {
+                start: [x0, y0],
+                end: [x0 + size, y0],
+            },
+            HatchEdge::Line {
+                start: [x0 + size, y0],
+                end: [x0 + size, y0 + size],
+            },
+            HatchEdge::Line {
+                start: [x0 + size, y0 + size],
+                end: [x0, y0 + size],
+            },
+            HatchEdge::Line {
+                start: [x0, y0 + size],
+                end: [x0, y0],
+            },
+        ]),
+        ..Default::default()
+    };
+    // 10x10 square with a 4x4 island and a 2x2 island inside the island
+    let mut hatch = Hatch {
+        boundary_paths: vec![
+            square(0.0, 0.0, 10.0),
+            square(3.0, 3.0, 4.0),
+            square(4.0, 4.0, 2.0),
+        ],
+        pattern_lines: vec![HatchPatternLine {
+            angle_degree: 0.0,
+            base_point: [0.0, 0.5],
+            offset: [0.0, 1.0],
+            dash_lengths: vec![],
+        }],
+        ..Default::default()
+    };
+    let resolution = Resolution::SegmentsPerTurn(16);
+    let length = |hatch: &Hatch| -> f64 {
+        hatch
+            .pattern_segments(&resolution)
+            .iter()
+            .map(|[a, b]| (b[0] - a[0]).hypot(b[1] - a[1]))
+            .sum()
+    };
+    // 10 lines of 10, minus 4 lines of 4 in the island, plus 2 lines of 2 in the inner island
+    assert!((length(&hatch) - (100.0 - 16.0 + 4.0)).abs() < 1e-9);
+    let polygons = hatch.fill_polygons(&resolution);
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].len(), 2);
+    assert_eq!(polygons[1].len(), 1);
+
+    hatch.style = HatchStyle::Outer;
+    assert!((length(&hatch) - 84.0).abs() < 1e-9);
+    hatch.style = HatchStyle::Ignore;
+    assert!((length(&hatch) - 100.0).abs() < 1e-9);
+    assert_eq!(
+        hatch.fill_polygons(&resolution),
+        vec![vec![vec![
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 10.0, 0.0],
+            [0.0, 10.0, 0.0]
+        ]]]
+    );
+
+    // dashes of 1 and spaces of 1 on the first line only
+    hatch.pattern_lines[0].dash_lengths = vec![1.0, -1.0];
+    hatch.pattern_lines[0].offset = [0.0, 20.0];
+    assert!((length(&hatch) - 5.0).abs() < 1e-9);
+
+    // a counterclockwise full circle
+    hatch.boundary_paths = vec![HatchBoundaryPath {
+        boundary: HatchBoundary::Edges(vec![HatchEdge::CircularArc {
+            center: [0.0, 0.0],
+            radius: 1.0,
+            start_degree: 0.0,
+            end_degree: 360.0,
+            is_counterclockwise: true,
+        }]),
+        ..Default::default()
+    }];
+    assert_eq!(hatch.boundary_loops(&resolution)[0].len(), 16);
+}
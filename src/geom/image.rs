@@ -0,0 +1,160 @@
+use crate::Image;
+
+impl Image {
+    // clip boundary vertices, or the rectangle of the whole frame given by two corners
+    fn boundary_vertices(&self, frame: [[f64; 2]; 2]) -> Vec<[f64; 2]> {
+        let rectangle = |p: &[f64; 2], q: &[f64; 2]| vec![*p, [q[0], p[1]], *q, [p[0], q[1]]];
+        match self.clip_boundary_vertices.as_slice() {
+            [p, q] if self.is_clipping_on => rectangle(p, q),
+            vertices if self.is_clipping_on && vertices.len() >= 3 => vertices.to_vec(),
+            _ => rectangle(&frame[0], &frame[1]),
+        }
+    }
+
+    /// The clipping boundary (or the whole image frame if clipping is off) in WCS.
+    ///
+    /// Boundary vertices are stored in pixel coordinates whose origin is the
+    /// center of the upper-left pixel with the y-axis pointing downward.
+    pub fn boundary_wcs(&self) -> Vec<[f64; 3]> {
+        let [w, h] = self.image_size;
+        let vertices = self.boundary_vertices([[-0.5, -0.5], [w - 0.5, h - 0.5]]);
+        let origin = super::add(
+            &self.insertion_point,
+            &super::sub(
+                &super::scale(&self.u_vector, 0.5),
+                &super::scale(&self.v_vector, 0.5),
+            ),
+        );
+        vertices
+            .iter()
+            .map(|p| {
+                super::add(
+                    &origin,
+                    &super::add(
+                        &super::scale(&self.u_vector, p[0]),
+                        &super::scale(&self.v_vector, h - p[1]),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// The clipping boundary (or the whole frame if clipping is off) of a WIPEOUT entity in WCS.
+    ///
+    /// Unlike those of an IMAGE, the boundary vertices of a WIPEOUT are normalized to its frame:
+    /// they range from -0.5 to 0.5 around the center, with the y-axis pointing downward.
+    /// A missing image size counts as a single pixel.
+    pub fn wipeout_boundary_wcs(&self) -> Vec<[f64; 3]> {
+        let [w, h] = self
+            .image_size
+            .map(|size| if size > 0.0 { size } else { 1.0 });
+        let (u, v) = (
+            super::scale(&self.u_vector, w),
+            super::scale(&self.v_vector, h),
+        );
+        self.boundary_vertices([[-0.5, -0.5], [0.5, 0.5]])
+            .iter()
+            .map(|p| {
+                super::add(
+                    &self.insertion_point,
+                    &super::add(&super::scale(&u, p[0] + 0.5), &super::scale(&v, 0.5 - p[1])),
+                )
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_wipeout_boundary() {
+    // a triangle whose apex is at the top of a 4 x 2 frame
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+WIPEOUT
+  5
+2F
+100
+AcDbEntity
+  8
+0
+100
+AcDbWipeout
+ 90
+0
+ 10
+1.0
+ 20
+2.0
+ 30
+0.0
+ 11
+4.0
+ 21
+0.0
+ 31
+0.0
+ 12
+0.0
+ 22
+2.0
+ 32
+0.0
+ 13
+1.0
+ 23
+1.0
+340
+0
+ 70
+7
+280
+1
+281
+50
+282
+50
+283
+0
+ 71
+2
+ 91
+4
+ 14
+-0.5
+ 24
+0.5
+ 14
+0.5
+ 24
+0.5
+ 14
+0.0
+ 24
+-0.5
+ 14
+-0.5
+ 24
+0.5
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let wipeout = match &doc.entities[0].entity {
+        crate::Entity::Wipeout(wipeout) => wipeout,
+        _ => panic!("not a wipeout"),
+    };
+    assert_eq!(
+        wipeout.wipeout_boundary_wcs(),
+        [
+            [1.0, 2.0, 0.0],
+            [5.0, 2.0, 0.0],
+            [3.0, 4.0, 0.0],
+            [1.0, 2.0, 0.0]
+        ]
+    );
+    let bbox = doc.entity_bounding_box(&doc.entities[0].entity);
+    assert_eq!((bbox.min, bbox.max), ([1.0, 2.0, 0.0], [5.0, 4.0, 0.0]));
+}
@@ -0,0 +1,270 @@
+use super::{add, norm, scale, sub, Resolution, Tessellate};
+use crate::*;
+
+// upper limit against degenerate dash lengths
+const MAX_PATTERN_REPEATS: usize = 1 << 16;
+
+/// A visible piece of a curve drawn with a linetype pattern.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Dash {
+    Line(Vec<[f64; 3]>),
+    Dot([f64; 3]),
+}
+
+impl LineType {
+    /// Splits a polyline into the dashes and dots of the pattern, scaled by `scale`.
+    ///
+    /// The pattern starts at the first point. Curves shorter than one pattern,
+    /// curves repeating the pattern too many times and continuous linetypes
+    /// are drawn as a single line.
+    /// Embedded texts and shapes are not drawn; their elements act as plain dashes or spaces.
+    pub fn apply(&self, points: &[[f64; 3]], scale: f64) -> Vec<Dash> {
+        let lengths = self
+            .pattern_lengths
+            .iter()
+            .map(|length| length * scale)
+            .collect::<Vec<_>>();
+        let pattern_length: f64 = lengths.iter().map(|length| length.abs()).sum();
+        let curve_length: f64 = points.windows(2).map(|w| norm(&sub(&w[1], &w[0]))).sum();
+        if points.len() < 2
+            || pattern_length <= 0.0
+            || curve_length < pattern_length
+            || curve_length / pattern_length > MAX_PATTERN_REPEATS as f64
+        {
+            return if points.len() < 2 {
+                vec![]
+            } else {
+                vec![Dash::Line(points.to_vec())]
+            };
+        }
+        let mut walker = PatternWalker {
+            lengths: &lengths,
+            index: 0,
+            remaining: 0.0,
+            current: None,
+            dashes: vec![],
+        };
+        walker.start_element(&points[0]);
+        for w in points.windows(2) {
+            walker.walk(&w[0], &w[1]);
+        }
+        walker.finish()
+    }
+}
+
+struct PatternWalker<'a> {
+    lengths: &'a [f64],
+    index: usize,
+    remaining: f64, // of the current element
+    current: Option<Vec<[f64; 3]>>,
+    dashes: Vec<Dash>,
+}
+
+impl PatternWalker<'_> {
+    // begins the element at `index`, emitting dots until a dash or space is reached
+    fn start_element(&mut self, p: &[f64; 3]) {
+        while self.lengths[self.index] == 0.0 {
+            self.dashes.push(Dash::Dot(*p));
+            self.index = (self.index + 1) % self.lengths.len();
+        }
+        let length = self.lengths[self.index];
+        self.remaining = length.abs();
+        if length > 0.0 {
+            self.current = Some(vec![*p]);
+        }
+    }
+
+    fn end_element(&mut self, p: &[f64; 3]) {
+        if let Some(mut points) = self.current.take() {
+            points.push(*p);
+            self.dashes.push(Dash::Line(points));
+        }
+        self.index = (self.index + 1) % self.lengths.len();
+        self.start_element(p);
+    }
+
+    fn walk(&mut self, p: &[f64; 3], q: &[f64; 3]) {
+        let d = sub(q, p);
+        let length = norm(&d);
+        let mut t = 0.0;
+        while length - t >= self.remaining {
+            t += self.remaining;
+            self.end_element(&add(p, &scale(&d, t / length)));
+        }
+        self.remaining -= length - t;
+        if let Some(points) = &mut self.current {
+            points.push(*q);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Dash> {
+        if let Some(points) = self.current.take() {
+            if points.len() >= 2 {
+                self.dashes.push(Dash::Line(points));
+            }
+        }
+        self.dashes
+    }
+}
+
+// pieces of a polyline along which the pattern runs, restarting at every vertex without PLINEGEN
+fn lw_polyline_pieces(polyline: &LwPolyline, resolution: &Resolution) -> Vec<Vec<[f64; 3]>> {
+    if polyline.is_continuous_pattern {
+        return vec![polyline.tessellate(resolution)];
+    }
+    polyline
+        .segments()
+        .into_iter()
+        .map(|segment| {
+            let vertex = |p: [f64; 2]| LwPolylineVertex {
+                coord: p,
+                ..Default::default()
+            };
+            let mut start = vertex(segment.start());
+            if let PolylineSegment::Arc { sweep_radian, .. } = segment {
+                start.bulge = Some((sweep_radian / 4.0).tan());
+            }
+            // only the vertices and the OCS matter for the tessellation
+            LwPolyline {
+                vertices: vec![start, vertex(segment.end())],
+                elevation: polyline.elevation,
+                extrusion_direction: polyline.extrusion_direction,
+                ..Default::default()
+            }
+            .tessellate(resolution)
+        })
+        .collect()
+}
+
+impl Document {
+    /// Finds an LTYPE table record by name (case-insensitive).
+    pub fn line_type(&self, name: &str) -> Option<&LineType> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find_map(|entry| match &entry.record {
+                TableRecord::LineType(line_type) if entry.name.eq_ignore_ascii_case(name) => {
+                    Some(line_type)
+                }
+                _ => None,
+            })
+    }
+
+    /// Draws a curve entity (LINE, CIRCLE, ARC, ELLIPSE, LWPOLYLINE, POLYLINE or SPLINE)
+    /// with its resolved linetype, scaled by `$LTSCALE` and the entity's linetype scale.
+    /// Without PLINEGEN the pattern restarts at every vertex of a polyline,
+    /// except along the curve of a spline-fit POLYLINE computed from its frame.
+    /// `None` for other entity types, including polygon and polyface meshes.
+    pub fn line_type_dashes(
+        &self,
+        entity: &Entity,
+        properties: &EffectiveProperties,
+        resolution: &Resolution,
+    ) -> Option<Vec<Dash>> {
+        let pieces = match entity {
+            Entity::Line(line) => vec![line.tessellate(resolution)],
+            Entity::Circle(circle) => vec![circle.tessellate(resolution)],
+            Entity::Arc(arc) => vec![arc.tessellate(resolution)],
+            Entity::Ellipse(ellipse) => vec![ellipse.tessellate(resolution)],
+            Entity::LwPolyline(polyline) => lw_polyline_pieces(polyline, resolution),
+            Entity::Polyline(polyline) if polyline.is_mesh() => return None,
+            Entity::Polyline(polyline)
+                if polyline.flags.continuous_linetype_pattern
+                    || polyline.spline_frame().is_some() =>
+            {
+                vec![polyline.tessellate(resolution)]
+            }
+            Entity::Polyline(polyline) => match polyline.to_lw_polyline() {
+                Some(lw_polyline) => lw_polyline_pieces(&lw_polyline, resolution),
+                // 3D polylines
+                None => polyline
+                    .tessellate(resolution)
+                    .windows(2)
+                    .map(<[_]>::to_vec)
+                    .collect(),
+            },
+            Entity::Spline(spline) => vec![spline.tessellate(resolution)],
+            _ => return None,
+        };
+        let global_scale = self
+            .header("$LTSCALE")
+            .and_then(|node| node.atoms.find(40))
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(1.0);
+        let line_type = self
+            .line_type(&properties.line_type)
+            .filter(|line_type| !line_type.is_continuous());
+        let dashes = pieces
+            .iter()
+            .flat_map(|points| match line_type {
+                Some(line_type) => {
+                    line_type.apply(points, global_scale * properties.line_type_scale)
+                }
+                None => vec![Dash::Line(points.clone())],
+            })
+            .collect();
+        Some(dashes)
+    }
+}
+
+#[test]
+fn test_line_type_apply() {
+    // dash 0.5, space 0.25, dot, space 0.25
+    let line_type = LineType {
+        pattern_lengths: vec![0.5, -0.25, 0.0, -0.25],
+        ..Default::default()
+    };
+    let points = [[0.0, 0.0, 0.0], [1.5, 0.0, 0.0], [1.5, 1.0, 0.0]];
+    let dashes = line_type.apply(&points, 2.0);
+    assert_eq!(
+        dashes,
+        vec![
+            Dash::Line(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]),
+            Dash::Dot([1.5, 0.0, 0.0]),
+            Dash::Line(vec![[1.5, 0.5, 0.0], [1.5, 1.0, 0.0]]),
+        ]
+    );
+    // shorter than one pattern
+    assert_eq!(
+        line_type.apply(&points[..2], 2.0),
+        vec![Dash::Line(points[..2].to_vec())]
+    );
+    // too many repeats
+    assert_eq!(
+        line_type.apply(&points, 1e-9),
+        vec![Dash::Line(points.to_vec())]
+    );
+
+    // the pattern restarts at every vertex of a POLYLINE without PLINEGEN
+    let properties = EffectiveProperties {
+        layer: "0".to_owned(),
+        color_number: 7,
+        color_rgb: None,
+        line_type: "Continuous".to_owned(),
+        line_weight: -3,
+        line_type_scale: 1.0,
+        transparency: 0.0,
+        is_visible: true,
+    };
+    let mut polyline = Polyline {
+        vertices: points
+            .iter()
+            .map(|&coord| Vertex {
+                coord,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let resolution = Resolution::SegmentsPerTurn(36);
+    let doc = Document::default();
+    let dashes = doc.line_type_dashes(
+        &Entity::Polyline(polyline.clone()),
+        &properties,
+        &resolution,
+    );
+    assert_eq!(dashes.map(|dashes| dashes.len()), Some(2));
+    polyline.flags.continuous_linetype_pattern = true;
+    let dashes = doc.line_type_dashes(&Entity::Polyline(polyline), &properties, &resolution);
+    assert_eq!(dashes, Some(vec![Dash::Line(points.to_vec())]));
+}
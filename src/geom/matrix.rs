@@ -0,0 +1,99 @@
+use super::{add, cross, dot, scale};
+
+/// 3D affine transformation `p' = linear * p + translation`.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Matrix {
+    pub linear: [[f64; 3]; 3], // rows
+    pub translation: [f64; 3],
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Matrix {
+    pub const IDENTITY: Self = Self {
+        linear: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        translation: [0.0, 0.0, 0.0],
+    };
+
+    pub fn translation(v: [f64; 3]) -> Self {
+        Self {
+            translation: v,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn scaling(s: [f64; 3]) -> Self {
+        Self {
+            linear: [[s[0], 0.0, 0.0], [0.0, s[1], 0.0], [0.0, 0.0, s[2]]],
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Counterclockwise rotation about the Z axis.
+    pub fn rotation_z(degree: f64) -> Self {
+        let (sin, cos) = degree.to_radians().sin_cos();
+        Self {
+            linear: [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]],
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Maps the unit axes to `x`, `y` and `z`, and the origin to `origin`.
+    pub fn from_axes(x: [f64; 3], y: [f64; 3], z: [f64; 3], origin: [f64; 3]) -> Self {
+        Self {
+            linear: [[x[0], y[0], z[0]], [x[1], y[1], z[1]], [x[2], y[2], z[2]]],
+            translation: origin,
+        }
+    }
+
+    /// The transformation applying `self` first, then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let columns = self.columns();
+        let mut linear = [[0.0; 3]; 3];
+        for (i, row) in linear.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = dot(&next.linear[i], &columns[j]);
+            }
+        }
+        Self {
+            linear,
+            translation: next.transform_point(&self.translation),
+        }
+    }
+
+    pub fn transform_point(&self, p: &[f64; 3]) -> [f64; 3] {
+        add(&self.transform_vector(p), &self.translation)
+    }
+
+    pub fn transform_vector(&self, v: &[f64; 3]) -> [f64; 3] {
+        let [r0, r1, r2] = &self.linear;
+        [dot(r0, v), dot(r1, v), dot(r2, v)]
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let [a, b, c] = &self.linear;
+        dot(a, &cross(b, c))
+    }
+
+    /// Transforms a plane normal, keeping the orientation of the plane:
+    /// for `n = u × v` the result is `M u × M v`. The result is not normalized.
+    pub fn transform_normal(&self, n: &[f64; 3]) -> [f64; 3] {
+        let [c0, c1, c2] = self.columns();
+        add(
+            &add(
+                &scale(&cross(&c1, &c2), n[0]),
+                &scale(&cross(&c2, &c0), n[1]),
+            ),
+            &scale(&cross(&c0, &c1), n[2]),
+        )
+    }
+
+    fn columns(&self) -> [[f64; 3]; 3] {
+        let column = |j: usize| [self.linear[0][j], self.linear[1][j], self.linear[2][j]];
+        [column(0), column(1), column(2)]
+    }
+}
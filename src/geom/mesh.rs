@@ -0,0 +1,124 @@
+use crate::Mesh;
+
+impl Mesh {
+    /// Faces of the control mesh as polygons.
+    /// The subdivision is not applied.
+    pub fn polygons(&self) -> Vec<Vec<[f64; 3]>> {
+        self.faces
+            .iter()
+            .map(|face| {
+                face.iter()
+                    .filter_map(|&i| self.vertices.get(i).copied())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Triangles made by fanning out each face from its first vertex.
+    pub fn triangles(&self) -> Vec<[[f64; 3]; 3]> {
+        self.polygons()
+            .iter()
+            .flat_map(|polygon| {
+                (2..polygon.len()).map(move |i| [polygon[0], polygon[i - 1], polygon[i]])
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_triangles() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+MESH
+  5
+30
+ 71
+2
+ 72
+0
+ 91
+0
+ 92
+5
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 10
+1.0
+ 20
+0.0
+ 30
+0.0
+ 10
+1.0
+ 20
+1.0
+ 30
+0.0
+ 10
+0.0
+ 20
+1.0
+ 30
+0.0
+ 10
+0.5
+ 20
+0.5
+ 30
+1.0
+ 93
+9
+ 90
+4
+ 90
+0
+ 90
+1
+ 90
+2
+ 90
+3
+ 90
+3
+ 90
+0
+ 90
+1
+ 90
+4
+ 94
+1
+ 90
+0
+ 90
+1
+ 95
+1
+140
+0.5
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let mesh = match &doc.entities[0].entity {
+        crate::Entity::Mesh(mesh) => mesh,
+        _ => panic!("not a mesh"),
+    };
+    assert_eq!(mesh.faces, [vec![0, 1, 2, 3], vec![0, 1, 4]]);
+    assert_eq!(mesh.edges, [[0, 1]]);
+    assert_eq!(mesh.creases, [0.5]);
+    let triangles = mesh.triangles();
+    assert_eq!(triangles.len(), 3);
+    assert_eq!(
+        triangles[1],
+        [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+}
@@ -0,0 +1,101 @@
+use crate::{MLine, MLineJustification, MLineStyle};
+
+impl MLine {
+    /// Expands the multiline into one polyline per style element.
+    ///
+    /// Each element is placed at its style offset (scaled and shifted by the justification)
+    /// measured perpendicular to the segments, which is realized by moving along the miter vector.
+    /// Closed multilines repeat their first point at the end.
+    /// Breaks in the elements (the segment parameters after the first) are not applied.
+    pub fn polylines(&self, style: &MLineStyle) -> Vec<Vec<[f64; 3]>> {
+        let offsets = style.elements.iter().map(|e| e.offset);
+        let shift = match self.justification {
+            MLineJustification::Top => -offsets.clone().fold(f64::NEG_INFINITY, f64::max),
+            MLineJustification::Zero => 0.0,
+            MLineJustification::Bottom => -offsets.clone().fold(f64::INFINITY, f64::min),
+        };
+        let normal = self.extrusion_direction.unwrap_or([0.0, 0.0, 1.0]);
+        offsets
+            .map(|offset| {
+                let distance = (offset + shift) * self.scale_factor;
+                let mut points = self
+                    .vertices
+                    .iter()
+                    .map(|vertex| {
+                        let left = super::cross(&normal, &vertex.direction);
+                        let cos = super::dot(&vertex.miter_direction, &left);
+                        if cos.abs() < 1e-9 {
+                            vertex.coord
+                        } else {
+                            let miter = super::scale(&vertex.miter_direction, distance / cos);
+                            super::add(&vertex.coord, &miter)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if self.flags.is_closed && !points.is_empty() {
+                    points.push(points[0]);
+                }
+                points
+            })
+            .collect()
+    }
+}
+
+impl crate::Document {
+    /// MLINESTYLE object referenced by the multiline, looked up by its handle and then by its name.
+    pub fn mline_style(&self, mline: &MLine) -> Option<&MLineStyle> {
+        let mut styles = self.objects.iter().filter_map(|node| match &node.object {
+            crate::Object::MLineStyle(style) => Some((node.header.handle, style)),
+            _ => None,
+        });
+        let by_handle = styles
+            .clone()
+            .find(|(handle, _)| *handle == mline.style_handle);
+        by_handle
+            .or_else(|| styles.find(|(_, style)| style.name == mline.style_name))
+            .map(|(_, style)| style)
+    }
+}
+
+#[test]
+fn test_polylines() {
+    use crate::{MLineStyleElement, MLineVertex};
+    let style = MLineStyle {
+        elements: vec![
+            MLineStyleElement {
+                offset: 0.5,
+                ..Default::default()
+            },
+            MLineStyleElement {
+                offset: -0.5,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let vertex = |coord, direction, miter_direction| MLineVertex {
+        coord,
+        direction,
+        miter_direction,
+        elements: vec![],
+    };
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let mline = MLine {
+        scale_factor: 2.0,
+        justification: MLineJustification::Top,
+        vertices: vec![
+            vertex([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+            vertex([10.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-s, s, 0.0]),
+            vertex([10.0, 10.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]),
+        ],
+        ..Default::default()
+    };
+    let polylines = mline.polylines(&style);
+    assert_eq!(
+        polylines[0],
+        [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0], [10.0, 10.0, 0.0]]
+    );
+    let p = polylines[1][1];
+    assert!((p[0] - 12.0).abs() < 1e-9 && (p[1] + 2.0).abs() < 1e-9);
+    assert_eq!(polylines[1][2], [12.0, 10.0, 0.0]);
+}
@@ -0,0 +1,75 @@
+mod bounding_box;
+mod construction_line;
+mod contour;
+mod dimension;
+mod ellipse;
+mod explode;
+mod hatch;
+mod image;
+mod line_type;
+mod matrix;
+mod mesh;
+mod mline;
+mod ocs;
+mod polyline;
+mod spatial_index;
+mod spline;
+mod tessellate;
+mod transform;
+mod view;
+mod viewport;
+
+pub use bounding_box::BoundingBox;
+pub use contour::{Contour, ContourGap, Contours};
+pub use dimension::DimensionGraphics;
+pub use line_type::Dash;
+pub use matrix::Matrix;
+pub use ocs::Ocs;
+pub use polyline::PolylineSegment;
+pub use spatial_index::SpatialIndex;
+pub use tessellate::{Resolution, Tessellate};
+pub use transform::Transform;
+
+pub(crate) fn add(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn scale(a: &[f64; 3], s: f64) -> [f64; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+pub(crate) fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn normalize(a: &[f64; 3]) -> [f64; 3] {
+    scale(a, 1.0 / dot(a, a).sqrt())
+}
+
+// the arbitrary axis algorithm
+pub(crate) fn arbitrary_axes(normal: &[f64; 3]) -> [[f64; 3]; 3] {
+    let az = normalize(normal);
+    let ax = if az[0].abs() < 1.0 / 64.0 && az[1].abs() < 1.0 / 64.0 {
+        normalize(&cross(&[0.0, 1.0, 0.0], &az))
+    } else {
+        normalize(&cross(&[0.0, 0.0, 1.0], &az))
+    };
+    let ay = normalize(&cross(&az, &ax));
+    [ax, ay, az]
+}
+
+pub(crate) fn norm(a: &[f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
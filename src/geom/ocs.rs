@@ -0,0 +1,155 @@
+use super::{add, arbitrary_axes, dot, scale};
+use crate::*;
+
+/// Object coordinate system of a planar entity, derived from its extrusion direction
+/// by the arbitrary axis algorithm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ocs {
+    pub axes: [[f64; 3]; 3], // x, y and z axes in WCS
+}
+
+impl Ocs {
+    pub fn new(extrusion_direction: &[f64; 3]) -> Self {
+        Self {
+            axes: arbitrary_axes(extrusion_direction),
+        }
+    }
+
+    fn from_option(extrusion_direction: &Option<[f64; 3]>) -> Self {
+        Self::new(&extrusion_direction.unwrap_or([0.0, 0.0, 1.0]))
+    }
+
+    pub fn is_wcs(&self) -> bool {
+        self.axes[2] == [0.0, 0.0, 1.0]
+    }
+
+    pub fn to_wcs(&self, p: &[f64; 3]) -> [f64; 3] {
+        let [ax, ay, az] = &self.axes;
+        add(&add(&scale(ax, p[0]), &scale(ay, p[1])), &scale(az, p[2]))
+    }
+
+    pub fn from_wcs(&self, p: &[f64; 3]) -> [f64; 3] {
+        let [ax, ay, az] = &self.axes;
+        [dot(p, ax), dot(p, ay), dot(p, az)]
+    }
+
+    /// Unit vector in WCS of a direction given as an angle in the OCS XY plane.
+    pub fn direction_to_wcs(&self, degree: f64) -> [f64; 3] {
+        let (sin, cos) = degree.to_radians().sin_cos();
+        self.to_wcs(&[cos, sin, 0.0])
+    }
+}
+
+impl Circle {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+    pub fn center_wcs(&self) -> [f64; 3] {
+        self.ocs().to_wcs(&self.center)
+    }
+    /// WCS point on the circle at an angle measured counterclockwise in OCS.
+    pub fn point_at_wcs(&self, degree: f64) -> [f64; 3] {
+        let (sin, cos) = degree.to_radians().sin_cos();
+        let [x, y, z] = self.center;
+        self.ocs()
+            .to_wcs(&[x + self.radius * cos, y + self.radius * sin, z])
+    }
+}
+
+impl Arc {
+    pub fn start_point_wcs(&self) -> [f64; 3] {
+        self.circle.point_at_wcs(self.start_degree)
+    }
+    pub fn end_point_wcs(&self) -> [f64; 3] {
+        self.circle.point_at_wcs(self.end_degree)
+    }
+}
+
+impl LwPolyline {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+    /// Vertex positions in WCS, at the polyline's elevation.
+    pub fn vertices_wcs(&self) -> Vec<[f64; 3]> {
+        let ocs = self.ocs();
+        let z = self.elevation.unwrap_or(0.0);
+        self.vertices
+            .iter()
+            .map(|v| ocs.to_wcs(&[v.coord[0], v.coord[1], z]))
+            .collect()
+    }
+}
+
+impl Polyline {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+}
+
+impl Hatch {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+}
+
+impl Point {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+    /// Direction of the X axis used to display the point, in WCS.
+    pub fn x_axis_wcs(&self) -> [f64; 3] {
+        self.ocs()
+            .direction_to_wcs(self.x_axis_degree.unwrap_or(0.0))
+    }
+}
+
+impl Text {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_vector)
+    }
+    pub fn point1_wcs(&self) -> [f64; 3] {
+        self.ocs().to_wcs(&self.point1)
+    }
+    pub fn point2_wcs(&self) -> [f64; 3] {
+        self.ocs().to_wcs(&self.point2)
+    }
+    /// Direction of the text baseline in WCS.
+    pub fn direction_wcs(&self) -> [f64; 3] {
+        self.ocs()
+            .direction_to_wcs(self.rotation_degree.unwrap_or(0.0))
+    }
+}
+
+impl Insert {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::new(&self.extrusion_direction)
+    }
+    pub fn insertion_point_wcs(&self) -> [f64; 3] {
+        self.ocs().to_wcs(&self.insertion_point)
+    }
+}
+
+impl Dimension {
+    pub fn ocs(&self) -> Ocs {
+        Ocs::from_option(&self.extrusion_direction)
+    }
+    pub fn text_mid_point_wcs(&self) -> [f64; 3] {
+        self.ocs().to_wcs(&self.text_mid_point)
+    }
+}
+
+#[test]
+fn test_mirrored_ocs() {
+    let circle = Circle {
+        center: [10.0, 5.0, 2.0],
+        radius: 1.0,
+        extrusion_direction: Some([0.0, 0.0, -1.0]),
+        ..Default::default()
+    };
+    assert_eq!(circle.center_wcs(), [-10.0, 5.0, -2.0]);
+    let p = circle.point_at_wcs(0.0);
+    assert!((p[0] + 11.0).abs() < 1e-9 && (p[1] - 5.0).abs() < 1e-9);
+    let ocs = Ocs::new(&[1.0, 1.0, 1.0]);
+    let q = ocs.from_wcs(&ocs.to_wcs(&[1.0, 2.0, 3.0]));
+    assert!((q[0] - 1.0).abs() < 1e-9 && (q[1] - 2.0).abs() < 1e-9 && (q[2] - 3.0).abs() < 1e-9);
+}
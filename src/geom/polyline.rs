@@ -0,0 +1,417 @@
+use super::Resolution;
+use crate::*;
+
+/// A segment of an LWPOLYLINE in OCS.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PolylineSegment {
+    Line {
+        start: [f64; 2],
+        end: [f64; 2],
+    },
+    Arc {
+        start: [f64; 2],
+        end: [f64; 2],
+        center: [f64; 2],
+        radius: f64,
+        start_radian: f64,
+        sweep_radian: f64, // positive if counterclockwise
+    },
+}
+
+impl PolylineSegment {
+    /// The segment from `start` to `end` with a bulge (tangent of a quarter of the sweep angle).
+    pub fn from_bulge(start: [f64; 2], end: [f64; 2], bulge: f64) -> Self {
+        if bulge == 0.0 || start == end {
+            return PolylineSegment::Line { start, end };
+        }
+        let (dx, dy) = (end[0] - start[0], end[1] - start[1]);
+        // offset of the center from the chord midpoint, to the left of the chord
+        let k = (1.0 - bulge * bulge) / (4.0 * bulge);
+        let center = [
+            (start[0] + end[0]) / 2.0 - k * dy,
+            (start[1] + end[1]) / 2.0 + k * dx,
+        ];
+        PolylineSegment::Arc {
+            start,
+            end,
+            center,
+            radius: (start[0] - center[0]).hypot(start[1] - center[1]),
+            start_radian: (start[1] - center[1]).atan2(start[0] - center[0]),
+            sweep_radian: 4.0 * bulge.atan(),
+        }
+    }
+
+    pub fn start(&self) -> [f64; 2] {
+        match *self {
+            PolylineSegment::Line { start, .. } | PolylineSegment::Arc { start, .. } => start,
+        }
+    }
+
+    pub fn end(&self) -> [f64; 2] {
+        match *self {
+            PolylineSegment::Line { end, .. } | PolylineSegment::Arc { end, .. } => end,
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        match *self {
+            PolylineSegment::Line { start, end } => (end[0] - start[0]).hypot(end[1] - start[1]),
+            PolylineSegment::Arc {
+                radius,
+                sweep_radian,
+                ..
+            } => radius * sweep_radian.abs(),
+        }
+    }
+
+    /// Point at a distance from the start along the segment.
+    pub fn point_at(&self, distance: f64) -> [f64; 2] {
+        match *self {
+            PolylineSegment::Line { start, end } => {
+                let length = self.length();
+                let t = if length > 0.0 { distance / length } else { 0.0 };
+                [
+                    start[0] + (end[0] - start[0]) * t,
+                    start[1] + (end[1] - start[1]) * t,
+                ]
+            }
+            PolylineSegment::Arc {
+                center,
+                radius,
+                start_radian,
+                sweep_radian,
+                ..
+            } => {
+                let angle = start_radian + sweep_radian.signum() * distance / radius;
+                [
+                    center[0] + radius * angle.cos(),
+                    center[1] + radius * angle.sin(),
+                ]
+            }
+        }
+    }
+
+    // signed area and centroid of the circular segment between the chord and the arc
+    fn circular_segment(&self) -> Option<(f64, [f64; 2])> {
+        match *self {
+            PolylineSegment::Line { .. } => None,
+            PolylineSegment::Arc {
+                center,
+                radius,
+                start_radian,
+                sweep_radian,
+                ..
+            } => {
+                let theta = sweep_radian.abs();
+                let area = radius * radius / 2.0 * (theta - theta.sin());
+                let distance =
+                    4.0 * radius * (theta / 2.0).sin().powi(3) / (3.0 * (theta - theta.sin()));
+                let (sin, cos) = (start_radian + sweep_radian / 2.0).sin_cos();
+                let centroid = [center[0] + distance * cos, center[1] + distance * sin];
+                Some((sweep_radian.signum() * area, centroid))
+            }
+        }
+    }
+
+    // whether a point is between the chord and the arc
+    fn is_in_circular_segment(&self, p: &[f64; 2]) -> bool {
+        match *self {
+            PolylineSegment::Line { .. } => false,
+            PolylineSegment::Arc {
+                start,
+                end,
+                center,
+                radius,
+                ..
+            } => {
+                let mid = self.point_at(self.length() / 2.0);
+                let side = |q: &[f64; 2]| {
+                    (end[0] - start[0]) * (q[1] - start[1])
+                        - (end[1] - start[1]) * (q[0] - start[0])
+                };
+                (p[0] - center[0]).hypot(p[1] - center[1]) < radius && side(p) * side(&mid) > 0.0
+            }
+        }
+    }
+}
+
+// 2D geometry in the OCS, ignoring the elevation
+impl LwPolyline {
+    /// Line and arc segments, including the closing segment of a closed polyline.
+    pub fn segments(&self) -> Vec<PolylineSegment> {
+        let n = self.vertices.len();
+        let count = if self.is_closed {
+            n
+        } else {
+            n.saturating_sub(1)
+        };
+        (0..count)
+            .map(|i| {
+                let (v, next) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+                PolylineSegment::from_bulge(v.coord, next.coord, v.bulge.unwrap_or(0.0))
+            })
+            .collect()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.segments().iter().map(PolylineSegment::length).sum()
+    }
+
+    /// Enclosed area, positive if counterclockwise.
+    /// An open polyline is treated as closed by a straight segment.
+    pub fn signed_area(&self) -> f64 {
+        self.area_and_moment().0
+    }
+
+    pub fn is_counterclockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Centroid of the enclosed area, `None` if the area is zero.
+    pub fn centroid(&self) -> Option<[f64; 2]> {
+        let (area, moment) = self.area_and_moment();
+        if area == 0.0 {
+            None
+        } else {
+            Some([moment[0] / area, moment[1] / area])
+        }
+    }
+
+    // signed area and its first moment, by a triangle fan over the chords plus the circular segments
+    fn area_and_moment(&self) -> (f64, [f64; 2]) {
+        let origin = match self.vertices.first() {
+            Some(v) => v.coord,
+            None => return (0.0, [0.0, 0.0]),
+        };
+        let (mut area, mut moment) = (0.0, [0.0, 0.0]);
+        for segment in self.segments() {
+            let (p, q) = (segment.start(), segment.end());
+            let a = ((p[0] - origin[0]) * (q[1] - origin[1])
+                - (p[1] - origin[1]) * (q[0] - origin[0]))
+                / 2.0;
+            area += a;
+            moment[0] += a * (origin[0] + p[0] + q[0]) / 3.0;
+            moment[1] += a * (origin[1] + p[1] + q[1]) / 3.0;
+            if let Some((a, c)) = segment.circular_segment() {
+                area += a;
+                moment[0] += a * c[0];
+                moment[1] += a * c[1];
+            }
+        }
+        (area, moment)
+    }
+
+    /// Point at a distance from the first vertex, `None` if out of the polyline.
+    pub fn point_at_distance(&self, distance: f64) -> Option<[f64; 2]> {
+        if distance < 0.0 {
+            return None;
+        }
+        let mut rest = distance;
+        for segment in self.segments() {
+            let length = segment.length();
+            if rest <= length {
+                return Some(segment.point_at(rest));
+            }
+            rest -= length;
+        }
+        None
+    }
+
+    /// Whether a point is inside a closed polyline (even-odd rule). Always false if not closed.
+    pub fn contains(&self, p: &[f64; 2]) -> bool {
+        if !self.is_closed {
+            return false;
+        }
+        let mut inside = false;
+        for segment in self.segments() {
+            let (a, b) = (segment.start(), segment.end());
+            if (a[1] > p[1]) != (b[1] > p[1])
+                && p[0] < a[0] + (p[1] - a[1]) * (b[0] - a[0]) / (b[1] - a[1])
+            {
+                inside = !inside;
+            }
+            if segment.is_in_circular_segment(p) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// Outlines of the wide segments as polygons, one per segment with a nonzero width.
+    /// The width changes linearly from the start width to the end width of each segment;
+    /// the constant width applies to vertices without their own widths.
+    pub fn width_outlines(&self, resolution: &Resolution) -> Vec<Vec<[f64; 2]>> {
+        let n = self.vertices.len();
+        let width = |w: Option<f64>| w.or(self.constant_width).unwrap_or(0.0);
+        self.segments()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, segment)| {
+                let v = &self.vertices[i % n];
+                let (w0, w1) = (width(v.start_width), width(v.end_width));
+                if w0 == 0.0 && w1 == 0.0 {
+                    return None;
+                }
+                let outline = match *segment {
+                    PolylineSegment::Line { start, end } => {
+                        let length = segment.length();
+                        if length == 0.0 {
+                            return None;
+                        }
+                        let normal = [-(end[1] - start[1]) / length, (end[0] - start[0]) / length];
+                        let offset = |p: &[f64; 2], w: f64| {
+                            [p[0] + normal[0] * w / 2.0, p[1] + normal[1] * w / 2.0]
+                        };
+                        vec![
+                            offset(&start, w0),
+                            offset(&end, w1),
+                            offset(&end, -w1),
+                            offset(&start, -w0),
+                        ]
+                    }
+                    PolylineSegment::Arc {
+                        center,
+                        radius,
+                        start_radian,
+                        sweep_radian,
+                        ..
+                    } => {
+                        let count = resolution.segment_count(radius, sweep_radian);
+                        let point = |k: usize, side: f64| {
+                            let t = k as f64 / count as f64;
+                            let r = radius + side * (w0 + (w1 - w0) * t) / 2.0;
+                            let (sin, cos) = (start_radian + sweep_radian * t).sin_cos();
+                            [center[0] + r * cos, center[1] + r * sin]
+                        };
+                        (0..=count)
+                            .map(|k| point(k, 1.0))
+                            .chain((0..=count).rev().map(|k| point(k, -1.0)))
+                            .collect()
+                    }
+                };
+                Some(outline)
+            })
+            .collect()
+    }
+}
+
+// POLYLINE entities, whose curve-fit and spline-fit vertices are stored in the file
+impl Polyline {
+    pub fn is_polyline_3d(&self) -> bool {
+        self.flags.polyline_3d
+    }
+
+    /// Whether the entity is a polygon mesh or a polyface mesh rather than a curve.
+    pub fn is_mesh(&self) -> bool {
+        self.flags.polygon_mesh_3d || self.flags.polyface_mesh
+    }
+
+    /// Vertices on the curve: the frame control points of a spline-fit polyline are left out.
+    pub fn curve_vertices(&self) -> impl Iterator<Item = &Vertex> {
+        self.vertices
+            .iter()
+            .filter(|v| !v.flags.is_spline_frame_control_point)
+    }
+
+    /// Degree and control points in WCS of a spline-fit polyline whose fitted vertices
+    /// are missing from the file, so that the curve must be computed from its frame.
+    pub(crate) fn spline_frame(&self) -> Option<(usize, Vec<[f64; 3]>)> {
+        if !self.flags.spline_fit_vertices || self.curve_vertices().next().is_some() {
+            return None;
+        }
+        let degree = match self.smooth_type {
+            Some(PolylineSmoothType::QuadraticBSpline) => 2,
+            _ => 3,
+        };
+        let ocs = self.ocs();
+        let mut points = self
+            .vertices
+            .iter()
+            .map(|v| self.vertex_wcs(&ocs, v))
+            .collect::<Vec<_>>();
+        if points.len() <= degree {
+            return None;
+        }
+        if self.flags.closed_polyline {
+            points.extend_from_within(..degree);
+        }
+        Some((degree, points))
+    }
+
+    pub(crate) fn vertex_wcs(&self, ocs: &super::Ocs, v: &Vertex) -> [f64; 3] {
+        if self.is_polyline_3d() || self.is_mesh() {
+            v.coord
+        } else {
+            ocs.to_wcs(&[v.coord[0], v.coord[1], self.elevation.unwrap_or(0.0)])
+        }
+    }
+
+    /// The curve of a 2D polyline as an LWPOLYLINE; `None` for 3D polylines and meshes.
+    /// The default widths fill in the missing vertex widths.
+    pub fn to_lw_polyline(&self) -> Option<LwPolyline> {
+        if self.is_polyline_3d() || self.is_mesh() {
+            return None;
+        }
+        Some(LwPolyline {
+            vertices: self
+                .curve_vertices()
+                .map(|v| LwPolylineVertex {
+                    coord: [v.coord[0], v.coord[1]],
+                    start_width: v.start_width.or(self.default_start_width),
+                    end_width: v.end_width.or(self.default_end_width),
+                    bulge: v.bulge,
+                })
+                .collect(),
+            is_continuous_pattern: self.flags.continuous_linetype_pattern,
+            is_closed: self.flags.closed_polyline,
+            constant_width: None,
+            elevation: self.elevation,
+            thickness: self.thickness,
+            extrusion_direction: self.extrusion_direction,
+        })
+    }
+}
+
+#[test]
+fn test_polyline_geometry() {
+    use std::f64::consts::PI;
+    // 2x2 square whose right side is replaced by an outward semicircle
+    let vertex = |x, y, bulge| LwPolylineVertex {
+        coord: [x, y],
+        bulge,
+        ..Default::default()
+    };
+    let mut polyline = LwPolyline {
+        vertices: vec![
+            vertex(0.0, 0.0, None),
+            vertex(2.0, 0.0, Some(1.0)),
+            vertex(2.0, 2.0, None),
+            vertex(0.0, 2.0, None),
+        ],
+        is_closed: true,
+        ..Default::default()
+    };
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(polyline.length(), 6.0 + PI));
+    assert!(close(polyline.signed_area(), 4.0 + PI / 2.0));
+    assert!(polyline.is_counterclockwise());
+    let centroid = polyline.centroid().unwrap();
+    assert!(close(
+        centroid[0],
+        (4.0 + PI + 2.0 / 3.0) / (4.0 + PI / 2.0)
+    ));
+    assert!(close(centroid[1], 1.0));
+    let p = polyline.point_at_distance(2.0 + PI / 2.0).unwrap();
+    assert!(close(p[0], 3.0) && close(p[1], 1.0));
+    assert!(polyline.contains(&[2.9, 1.0]));
+    assert!(!polyline.contains(&[2.9, 0.2]));
+    assert!(polyline.contains(&[1.0, 1.0]));
+
+    polyline.constant_width = Some(0.5);
+    let outlines = polyline.width_outlines(&Resolution::SegmentsPerTurn(8));
+    assert_eq!(outlines.len(), 4);
+    assert_eq!(
+        outlines[0],
+        vec![[0.0, 0.25], [2.0, 0.25], [2.0, -0.25], [0.0, -0.25]]
+    );
+}
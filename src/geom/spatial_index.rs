@@ -0,0 +1,425 @@
+use super::{BoundingBox, Resolution, Tessellate};
+use crate::*;
+use std::collections::{HashMap, HashSet};
+
+// maximum number of children of a node before it is split
+const MAX_CHILDREN: usize = 16;
+
+type Rect = [[f64; 2]; 2];
+
+fn union(a: &Rect, b: &Rect) -> Rect {
+    [
+        [a[0][0].min(b[0][0]), a[0][1].min(b[0][1])],
+        [a[1][0].max(b[1][0]), a[1][1].max(b[1][1])],
+    ]
+}
+
+fn area(r: &Rect) -> f64 {
+    (r[1][0] - r[0][0]) * (r[1][1] - r[0][1])
+}
+
+fn intersects(a: &Rect, b: &Rect) -> bool {
+    a[0][0] <= b[1][0] && b[0][0] <= a[1][0] && a[0][1] <= b[1][1] && b[0][1] <= a[1][1]
+}
+
+fn rect_distance(r: &Rect, p: &[f64; 2]) -> f64 {
+    let dx = (r[0][0] - p[0]).max(p[0] - r[1][0]).max(0.0);
+    let dy = (r[0][1] - p[1]).max(p[1] - r[1][1]).max(0.0);
+    dx.hypot(dy)
+}
+
+fn segment_distance(a: &[f64; 2], b: &[f64; 2], p: &[f64; 2]) -> f64 {
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let length2 = d[0] * d[0] + d[1] * d[1];
+    let t = if length2 > 0.0 {
+        (((p[0] - a[0]) * d[0] + (p[1] - a[1]) * d[1]) / length2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (a[0] + d[0] * t - p[0]).hypot(a[1] + d[1] * t - p[1])
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    handle: u32,
+    rect: Rect,
+    shape: Vec<[f64; 2]>, // polyline for exact distances, empty to use the rectangle
+}
+
+impl Entry {
+    fn distance(&self, p: &[f64; 2]) -> f64 {
+        match self.shape.len() {
+            0 => rect_distance(&self.rect, p),
+            1 => (self.shape[0][0] - p[0]).hypot(self.shape[0][1] - p[1]),
+            _ => self
+                .shape
+                .windows(2)
+                .map(|w| segment_distance(&w[0], &w[1], p))
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(Vec<Entry>),
+    Branch(Vec<(Rect, Node)>),
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Node::Leaf(vec![])
+    }
+}
+
+impl Node {
+    fn len(&self) -> usize {
+        match self {
+            Node::Leaf(entries) => entries.len(),
+            Node::Branch(children) => children.len(),
+        }
+    }
+
+    fn rect(&self) -> Option<Rect> {
+        let rects: Vec<Rect> = match self {
+            Node::Leaf(entries) => entries.iter().map(|entry| entry.rect).collect(),
+            Node::Branch(children) => children.iter().map(|(rect, _)| *rect).collect(),
+        };
+        rects.into_iter().reduce(|a, b| union(&a, &b))
+    }
+
+    // inserts an entry, returning a new sibling if the node was split
+    fn insert(&mut self, entry: Entry) -> Option<Node> {
+        match self {
+            Node::Leaf(entries) => {
+                entries.push(entry);
+                if entries.len() > MAX_CHILDREN {
+                    let half = split(entries, |entry| entry.rect);
+                    return Some(Node::Leaf(half));
+                }
+            }
+            Node::Branch(children) => {
+                // the child needing the least enlargement, then the smallest
+                let (_, (rect, child)) = children
+                    .iter_mut()
+                    .map(|(rect, child)| {
+                        let enlargement = area(&union(rect, &entry.rect)) - area(rect);
+                        ((enlargement, area(rect)), (rect, child))
+                    })
+                    .min_by(|(a, _), (b, _)| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+                    .unwrap();
+                *rect = union(rect, &entry.rect);
+                if let Some(sibling) = child.insert(entry) {
+                    *rect = child.rect().unwrap();
+                    children.push((sibling.rect().unwrap(), sibling));
+                    if children.len() > MAX_CHILDREN {
+                        let half = split(children, |(rect, _)| *rect);
+                        return Some(Node::Branch(half));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    // removes the entries of a handle within `rect`, returning whether any was removed
+    fn remove(&mut self, handle: u32, rect: &Rect) -> bool {
+        match self {
+            Node::Leaf(entries) => {
+                let len = entries.len();
+                entries.retain(|entry| entry.handle != handle);
+                entries.len() != len
+            }
+            Node::Branch(children) => {
+                let mut is_removed = false;
+                for (child_rect, child) in children.iter_mut() {
+                    if intersects(child_rect, rect) && child.remove(handle, rect) {
+                        is_removed = true;
+                        if let Some(r) = child.rect() {
+                            *child_rect = r;
+                        }
+                    }
+                }
+                children.retain(|(_, child)| child.len() > 0);
+                is_removed
+            }
+        }
+    }
+
+    fn query(&self, rect: &Rect, found: &mut Vec<u32>, seen: &mut HashSet<u32>) {
+        match self {
+            Node::Leaf(entries) => {
+                for entry in entries {
+                    if intersects(&entry.rect, rect) && seen.insert(entry.handle) {
+                        found.push(entry.handle);
+                    }
+                }
+            }
+            Node::Branch(children) => {
+                for (child_rect, child) in children {
+                    if intersects(child_rect, rect) {
+                        child.query(rect, found, seen);
+                    }
+                }
+            }
+        }
+    }
+
+    // depth-first branch and bound, visiting the closest children first
+    fn nearest(&self, p: &[f64; 2], best: &mut Option<(u32, f64)>) {
+        let bound = |best: &Option<(u32, f64)>| best.map_or(f64::INFINITY, |(_, d)| d);
+        match self {
+            Node::Leaf(entries) => {
+                for entry in entries {
+                    if rect_distance(&entry.rect, p) < bound(best) {
+                        let d = entry.distance(p);
+                        if d < bound(best) {
+                            *best = Some((entry.handle, d));
+                        }
+                    }
+                }
+            }
+            Node::Branch(children) => {
+                let mut order = children
+                    .iter()
+                    .map(|(rect, child)| (rect_distance(rect, p), child))
+                    .collect::<Vec<_>>();
+                order.sort_by(|a, b| a.0.total_cmp(&b.0));
+                for (d, child) in order {
+                    if d >= bound(best) {
+                        break;
+                    }
+                    child.nearest(p, best);
+                }
+            }
+        }
+    }
+}
+
+// splits the items in two halves along the longer side of their extent, returning the upper half
+fn split<T>(items: &mut Vec<T>, rect: impl Fn(&T) -> Rect) -> Vec<T> {
+    let extent = items
+        .iter()
+        .map(&rect)
+        .reduce(|a, b| union(&a, &b))
+        .unwrap();
+    let axis = if extent[1][0] - extent[0][0] >= extent[1][1] - extent[0][1] {
+        0
+    } else {
+        1
+    };
+    let center = |item: &T| {
+        let r = rect(item);
+        r[0][axis] + r[1][axis]
+    };
+    items.sort_by(|a, b| center(a).total_cmp(&center(b)));
+    items.split_off(items.len() / 2)
+}
+
+/// R-tree of entities over their extents in the WCS XY plane, keyed by entity handle.
+/// Handle 0, which stands for a missing handle, is not a valid key.
+///
+/// Curves (LINE, CIRCLE, ARC, ELLIPSE and LWPOLYLINE) are measured by their shapes
+/// in nearest queries; other entities by their bounding boxes.
+/// With INSERTs expanded, each entity of a block reference is indexed separately
+/// under the handle of the INSERT.
+#[derive(Debug, Clone, Default)]
+pub struct SpatialIndex {
+    root: Node,
+    rects: HashMap<u32, Rect>, // extent of the entries of each handle
+    expand_inserts: bool,
+}
+
+impl SpatialIndex {
+    pub fn new(expand_inserts: bool) -> Self {
+        Self {
+            expand_inserts,
+            ..Default::default()
+        }
+    }
+
+    /// Number of indexed handles.
+    pub fn len(&self) -> usize {
+        self.rects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    fn insert_entry(&mut self, entry: Entry) {
+        let rect = match self.rects.get(&entry.handle) {
+            Some(rect) => union(rect, &entry.rect),
+            None => entry.rect,
+        };
+        self.rects.insert(entry.handle, rect);
+        if let Some(sibling) = self.root.insert(entry) {
+            let node = std::mem::take(&mut self.root);
+            self.root = Node::Branch(vec![
+                (node.rect().unwrap(), node),
+                (sibling.rect().unwrap(), sibling),
+            ]);
+        }
+    }
+
+    /// Adds an entry for a handle with a box, returning whether it was added.
+    /// Handle 0 and empty boxes are refused.
+    pub fn insert(&mut self, handle: u32, bbox: &BoundingBox) -> bool {
+        if handle == 0 || bbox.is_empty() {
+            return false;
+        }
+        self.insert_entry(Entry {
+            handle,
+            rect: bbox.xy(),
+            shape: vec![],
+        });
+        true
+    }
+
+    /// Adds an entity of `doc`, replacing the entries of its handle,
+    /// and returns whether it was indexed.
+    /// Entities without handles (handle 0, as in R12 files without them) are refused,
+    /// since they could not be told apart in the results.
+    /// Entities without extents (RAY, XLINE and ACIS entities) are not indexed.
+    pub fn insert_entity(&mut self, doc: &Document, node: &EntityNode) -> bool {
+        let handle = node.header.handle;
+        if handle == 0 {
+            return false;
+        }
+        self.remove(handle);
+        match &node.entity {
+            Entity::Insert(insert) if self.expand_inserts => {
+                let mut is_inserted = false;
+                for child in doc.explode_insert(insert) {
+                    is_inserted |= self.insert_shape(doc, handle, &child.entity);
+                }
+                is_inserted
+            }
+            entity => self.insert_shape(doc, handle, entity),
+        }
+    }
+
+    fn insert_shape(&mut self, doc: &Document, handle: u32, entity: &Entity) -> bool {
+        let bbox = doc.entity_bounding_box(entity);
+        if bbox.is_empty() {
+            return false;
+        }
+        let resolution = Resolution::SegmentsPerTurn(64);
+        let points = match entity {
+            Entity::Line(line) => line.tessellate(&resolution),
+            Entity::Circle(circle) => circle.tessellate(&resolution),
+            Entity::Arc(arc) => arc.tessellate(&resolution),
+            Entity::Ellipse(ellipse) => ellipse.tessellate(&resolution),
+            Entity::LwPolyline(polyline) => polyline.tessellate(&resolution),
+            Entity::Polyline(polyline) => polyline.tessellate(&resolution),
+            Entity::Spline(spline) => spline.tessellate(&resolution),
+            _ => vec![],
+        };
+        self.insert_entry(Entry {
+            handle,
+            rect: bbox.xy(),
+            shape: points.iter().map(|p| [p[0], p[1]]).collect(),
+        });
+        true
+    }
+
+    /// Removes the entries of a handle, returning whether it was indexed.
+    pub fn remove(&mut self, handle: u32) -> bool {
+        match self.rects.remove(&handle) {
+            Some(rect) => {
+                self.root.remove(handle, &rect);
+                // collapse a root left with a single branch
+                while let Node::Branch(children) = &mut self.root {
+                    if children.len() != 1 {
+                        break;
+                    }
+                    self.root = children.pop().unwrap().1;
+                }
+                if self.root.len() == 0 {
+                    self.root = Node::default();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Handles of the entries intersecting a box in the XY plane, each reported once.
+    pub fn query(&self, bbox: &BoundingBox) -> Vec<u32> {
+        let mut found = vec![];
+        if !bbox.is_empty() {
+            self.root.query(&bbox.xy(), &mut found, &mut HashSet::new());
+        }
+        found
+    }
+
+    /// Handle of the entry nearest to a point in the XY plane, with its distance.
+    pub fn nearest(&self, p: &[f64; 2]) -> Option<(u32, f64)> {
+        let mut best = None;
+        self.root.nearest(p, &mut best);
+        best
+    }
+}
+
+impl Document {
+    /// Spatial index of the visible entities of the ENTITIES section in a space.
+    /// Entities without handles are left out.
+    pub fn spatial_index(&self, space: Space, expand_inserts: bool) -> SpatialIndex {
+        let mut index = SpatialIndex::new(expand_inserts);
+        for node in &self.entities {
+            if node.header.space == space && node.header.is_visible {
+                index.insert_entity(self, node);
+            }
+        }
+        index
+    }
+}
+
+#[test]
+fn test_spatial_index() {
+    let line = |handle: u32, x: f64, y: f64| EntityNode {
+        header: EntityHeader {
+            handle,
+            is_visible: true,
+            ..Default::default()
+        },
+        entity: Entity::Line(Line {
+            p1: [x, y, 0.0],
+            p2: [x + 1.0, y + 1.0, 0.0],
+            ..Default::default()
+        }),
+    };
+    // a 20x20 grid of diagonal lines, enough to split the root
+    let doc = Document {
+        entities: (0..400)
+            .map(|i| line(i + 1, (i % 20) as f64 * 2.0, (i / 20) as f64 * 2.0))
+            .collect(),
+        ..Default::default()
+    };
+    let mut index = doc.spatial_index(Space::ModelSpace, false);
+    assert_eq!(index.len(), 400);
+
+    let rect = BoundingBox {
+        min: [2.5, 2.5, 0.0],
+        max: [6.5, 4.5, 0.0],
+    };
+    let mut found = index.query(&rect);
+    found.sort_unstable();
+    // lines from (2, 2), (4, 2), (6, 2), (2, 4), (4, 4) and (6, 4)
+    assert_eq!(found, vec![22, 23, 24, 42, 43, 44]);
+
+    // outside every box, nearest to the start of line 44
+    let (handle, distance) = index.nearest(&[5.9, 4.1]).unwrap();
+    assert_eq!(handle, 44);
+    assert!((distance - 0.1 * 2f64.sqrt()).abs() < 1e-9);
+
+    assert!(index.remove(44));
+    assert!(!index.remove(44));
+    assert_eq!(index.len(), 399);
+    assert_eq!(index.nearest(&[5.9, 4.1]).unwrap().0, 43);
+    assert!(index.insert_entity(&doc, &line(1000, 5.8, 4.0)));
+    assert_eq!(index.nearest(&[5.9, 4.1]).unwrap().0, 1000);
+    // entities without handles
+    assert!(!index.insert_entity(&doc, &line(0, 5.8, 4.0)));
+    assert_eq!(index.len(), 400);
+}
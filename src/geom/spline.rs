@@ -0,0 +1,243 @@
+use super::{dot, norm, scale, sub, Resolution, Tessellate};
+use crate::*;
+use std::f64::consts::PI;
+
+// limit of the halvings of a knot span to meet a tolerance
+const MAX_SUBDIVISION_DEPTH: usize = 12;
+
+/// A B-spline curve by its control data, shared by SPLINE entities, spline edges of hatches
+/// and spline-fit POLYLINE entities.
+pub(crate) struct BSpline<'a> {
+    pub degree: usize,
+    pub knots: &'a [f64],
+    pub control_points: &'a [[f64; 3]],
+    pub weights: &'a [f64], // empty if not rational
+}
+
+impl BSpline<'_> {
+    fn is_valid(&self) -> bool {
+        let (p, n) = (self.degree, self.control_points.len());
+        n > p && self.knots.len() == n + p + 1
+    }
+
+    /// Point at a parameter, by de Boor's algorithm on homogeneous coordinates.
+    /// `None` if the knots do not match the control points and the degree.
+    pub fn point_at(&self, t: f64) -> Option<[f64; 3]> {
+        if !self.is_valid() {
+            return None;
+        }
+        let (p, n) = (self.degree, self.control_points.len());
+        let knots = self.knots;
+        let mut k = p;
+        while k + 1 < n && knots[k + 1] <= t {
+            k += 1;
+        }
+        let mut d = (0..=p)
+            .map(|j| {
+                let i = j + k - p;
+                let (c, w) = (
+                    self.control_points[i],
+                    self.weights.get(i).copied().unwrap_or(1.0),
+                );
+                [c[0] * w, c[1] * w, c[2] * w, w]
+            })
+            .collect::<Vec<_>>();
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let (lo, hi) = (knots[j + k - p], knots[j + 1 + k - r]);
+                let alpha = if hi > lo { (t - lo) / (hi - lo) } else { 0.0 };
+                let (prev, cur) = (d[j - 1], d[j]);
+                d[j] = [0, 1, 2, 3].map(|c| (1.0 - alpha) * prev[c] + alpha * cur[c]);
+            }
+        }
+        let [x, y, z, w] = d[p];
+        Some([x / w, y / w, z / w])
+    }
+
+    /// Points over the valid parameter range, `None` for invalid control data.
+    ///
+    /// With [`Resolution::Tolerance`], each knot span is split into `degree` segments
+    /// which are halved until the midpoints of the curve are within the tolerance of the chords.
+    /// With [`Resolution::SegmentsPerTurn`], each knot span is treated as a quarter turn
+    /// of a circle as large as an average control leg.
+    pub fn tessellate(&self, resolution: &Resolution) -> Option<Vec<[f64; 3]>> {
+        if !self.is_valid() {
+            return None;
+        }
+        let (p, n) = (self.degree, self.control_points.len());
+        let count = match *resolution {
+            Resolution::Tolerance(_) => p.max(1),
+            Resolution::SegmentsPerTurn(_) => {
+                let leg = self
+                    .control_points
+                    .windows(2)
+                    .map(|w| norm(&sub(&w[1], &w[0])))
+                    .sum::<f64>()
+                    / (n - 1).max(1) as f64;
+                resolution.segment_count(leg, PI / 2.0)
+            }
+        };
+        let mut points = vec![];
+        for k in p..n {
+            let (lo, hi) = (self.knots[k], self.knots[k + 1]);
+            if hi <= lo {
+                continue;
+            }
+            // the end of a span is evaluated on the span itself
+            let at = |t: f64| self.point_at(t.min(hi - (hi - lo) * 1e-12).max(lo));
+            let mut start = (lo, at(lo)?);
+            if points.is_empty() {
+                points.push(start.1);
+            }
+            for i in 1..=count {
+                let t = lo + (hi - lo) * i as f64 / count as f64;
+                let end = (t, at(t)?);
+                if let Resolution::Tolerance(tolerance) = *resolution {
+                    refine(&at, start, end, tolerance, 0, &mut points)?;
+                }
+                points.push(end.1);
+                start = end;
+            }
+        }
+        if let (Some(last), Some(end)) = (points.last_mut(), self.control_points.last()) {
+            // a clamped spline ends at its last control point
+            if self.knots[n..].iter().all(|&u| u == self.knots[n]) {
+                *last = *end;
+            }
+        }
+        Some(points)
+    }
+
+    /// Uniform knots for `count` control points of a POLYLINE spline frame: clamped at
+    /// both ends if open, unclamped if closed, where the first `degree` points are repeated.
+    pub fn uniform_knots(degree: usize, count: usize, is_closed: bool) -> Vec<f64> {
+        if is_closed {
+            (0..count + degree + 1).map(|i| i as f64).collect()
+        } else {
+            let last = count.saturating_sub(degree) as f64;
+            (0..count + degree + 1)
+                .map(|i| (i as f64 - degree as f64).clamp(0.0, last))
+                .collect()
+        }
+    }
+}
+
+// Adds the points between `start` and `end` that bring the chords within the tolerance.
+fn refine(
+    at: &impl Fn(f64) -> Option<[f64; 3]>,
+    start: (f64, [f64; 3]),
+    end: (f64, [f64; 3]),
+    tolerance: f64,
+    depth: usize,
+    points: &mut Vec<[f64; 3]>,
+) -> Option<()> {
+    let t = (start.0 + end.0) / 2.0;
+    let mid = (t, at(t)?);
+    let chord = sub(&end.1, &start.1);
+    let offset = sub(&mid.1, &start.1);
+    let length = dot(&chord, &chord);
+    let along = if length > 0.0 {
+        (dot(&offset, &chord) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let deviation = norm(&sub(&offset, &scale(&chord, along)));
+    if depth < MAX_SUBDIVISION_DEPTH && deviation > tolerance {
+        refine(at, start, mid, tolerance, depth + 1, points)?;
+        points.push(mid.1);
+        refine(at, mid, end, tolerance, depth + 1, points)?;
+    }
+    Some(())
+}
+
+impl Spline {
+    fn b_spline(&self) -> BSpline<'_> {
+        BSpline {
+            degree: self.degree,
+            knots: &self.knots,
+            control_points: &self.control_points,
+            weights: if self.flags.is_rational {
+                &self.weights
+            } else {
+                &[]
+            },
+        }
+    }
+
+    /// Point at a parameter of the knot vector; `None` without valid control data.
+    pub fn point_at(&self, t: f64) -> Option<[f64; 3]> {
+        self.b_spline().point_at(t)
+    }
+}
+
+impl Tessellate for Spline {
+    /// Splines without valid control data, such as those defined only by fit points,
+    /// fall back to the straight segments through their fit points, or to their control polygon,
+    /// without interpolation and regardless of the resolution.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        self.b_spline().tessellate(resolution).unwrap_or_else(|| {
+            if self.control_points.is_empty() {
+                self.fit_points.clone()
+            } else {
+                self.control_points.clone()
+            }
+        })
+    }
+}
+
+#[test]
+fn test_spline() {
+    // a quadratic Bezier curve as a clamped B-spline
+    let spline = Spline {
+        degree: 2,
+        knots: vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+        control_points: vec![[0.0, 0.0, 0.0], [1.0, 2.0, 0.0], [2.0, 0.0, 0.0]],
+        ..Default::default()
+    };
+    assert_eq!(spline.point_at(0.5), Some([1.0, 1.0, 0.0]));
+    let points = spline.tessellate(&Resolution::SegmentsPerTurn(16));
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[0], [0.0, 0.0, 0.0]);
+    assert_eq!(points[4], [2.0, 0.0, 0.0]);
+
+    // a rational quadratic spline for a quarter circle
+    let w = 0.5f64.sqrt();
+    let arc = Spline {
+        flags: SplineFlags {
+            is_rational: true,
+            ..Default::default()
+        },
+        weights: vec![1.0, w, 1.0],
+        control_points: vec![[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]],
+        ..spline.clone()
+    };
+    for p in arc.tessellate(&Resolution::SegmentsPerTurn(64)) {
+        assert!((p[0].hypot(p[1]) - 1.0).abs() < 1e-9);
+    }
+
+    let tolerance = 1e-4;
+    let points = arc.tessellate(&Resolution::Tolerance(tolerance));
+    for pair in points.windows(2) {
+        let mid = super::scale(&super::add(&pair[0], &pair[1]), 0.5);
+        assert!(1.0 - super::norm(&mid) <= tolerance);
+    }
+    // no more than twice as many segments as needed by a circular arc
+    let count = Resolution::Tolerance(tolerance).segment_count(1.0, PI / 2.0);
+    assert!(points.len() <= 2 * count + 1);
+
+    // fit points only
+    let fit = Spline {
+        degree: 3,
+        fit_points: vec![[0.0, 0.0, 0.0], [1.0, 1.0, 0.0]],
+        ..Default::default()
+    };
+    assert_eq!(
+        fit.tessellate(&Resolution::SegmentsPerTurn(16)),
+        fit.fit_points
+    );
+
+    assert_eq!(
+        BSpline::uniform_knots(2, 4, false),
+        [0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0]
+    );
+}
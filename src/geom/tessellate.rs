@@ -0,0 +1,183 @@
+use super::{spline::BSpline, PolylineSegment};
+use crate::*;
+use std::f64::consts::PI;
+
+// upper limit against non-positive tolerances
+const MAX_SEGMENTS_PER_TURN: usize = 1 << 16;
+
+/// How finely curves are approximated by line segments.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Resolution {
+    /// Maximum distance between a curve and its chords, in drawing units.
+    Tolerance(f64),
+    /// Number of segments per full turn; arcs get a proportional share.
+    SegmentsPerTurn(usize),
+}
+
+impl Resolution {
+    /// Number of segments for an arc of `radius` spanning `sweep` radians.
+    pub fn segment_count(&self, radius: f64, sweep: f64) -> usize {
+        let turns = sweep.abs() / (2.0 * PI);
+        let per_turn = match *self {
+            Resolution::SegmentsPerTurn(n) => n,
+            Resolution::Tolerance(tolerance) if tolerance >= radius => 4,
+            Resolution::Tolerance(tolerance) => {
+                let step = 2.0 * (1.0 - tolerance / radius).acos();
+                (2.0 * PI / step).ceil().min(MAX_SEGMENTS_PER_TURN as f64) as usize
+            }
+        };
+        ((per_turn as f64 * turns).ceil() as usize).max(1)
+    }
+}
+
+/// Approximation of a curve by a polyline in WCS.
+pub trait Tessellate {
+    /// Points from the start to the end of the curve;
+    /// closed curves repeat their first point at the end.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]>;
+}
+
+impl Tessellate for Line {
+    fn tessellate(&self, _: &Resolution) -> Vec<[f64; 3]> {
+        vec![self.p1, self.p2]
+    }
+}
+
+impl Tessellate for Circle {
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        let n = resolution.segment_count(self.radius, 2.0 * PI).max(3);
+        (0..=n)
+            .map(|i| self.point_at_wcs(360.0 * (i % n) as f64 / n as f64))
+            .collect()
+    }
+}
+
+impl Tessellate for Arc {
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        let sweep = (self.end_degree - self.start_degree).rem_euclid(360.0);
+        let n = resolution.segment_count(self.circle.radius, sweep.to_radians());
+        (0..=n)
+            .map(|i| {
+                self.circle
+                    .point_at_wcs(self.start_degree + sweep * i as f64 / n as f64)
+            })
+            .collect()
+    }
+}
+
+impl Tessellate for Ellipse {
+    /// Segments are evenly spaced in the parameter; the tolerance is met along the major axis.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        let (start, mut end) = (self.start_parameter, self.end_parameter);
+        while end <= start {
+            end += 2.0 * PI;
+        }
+        let radius = super::norm(&self.major_axis);
+        let n = resolution.segment_count(radius, end - start);
+        (0..=n)
+            .map(|i| self.point_at(start + (end - start) * i as f64 / n as f64))
+            .collect()
+    }
+}
+
+impl Tessellate for LwPolyline {
+    /// Bulged segments are approximated as arcs; widths are ignored.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        let ocs = self.ocs();
+        let z = self.elevation.unwrap_or(0.0);
+        let to_wcs = |p: &[f64; 2]| ocs.to_wcs(&[p[0], p[1], z]);
+        let mut points = self
+            .vertices
+            .first()
+            .map(|v| vec![to_wcs(&v.coord)])
+            .unwrap_or_default();
+        for segment in self.segments() {
+            if let PolylineSegment::Arc {
+                radius,
+                sweep_radian,
+                ..
+            } = segment
+            {
+                let count = resolution.segment_count(radius, sweep_radian);
+                let length = segment.length();
+                for k in 1..count {
+                    points.push(to_wcs(&segment.point_at(length * k as f64 / count as f64)));
+                }
+            }
+            points.push(to_wcs(&segment.end()));
+        }
+        points
+    }
+}
+
+impl Tessellate for Polyline {
+    /// 2D polylines are tessellated as LWPOLYLINEs and 3D polylines join their vertices.
+    /// A spline-fit polyline whose fitted vertices are missing is computed from its frame.
+    /// Polygon and polyface meshes are surfaces and give no points.
+    fn tessellate(&self, resolution: &Resolution) -> Vec<[f64; 3]> {
+        if self.is_mesh() {
+            return vec![];
+        }
+        if let Some((degree, control_points)) = self.spline_frame() {
+            let knots =
+                BSpline::uniform_knots(degree, control_points.len(), self.flags.closed_polyline);
+            let spline = BSpline {
+                degree,
+                knots: &knots,
+                control_points: &control_points,
+                weights: &[],
+            };
+            return spline.tessellate(resolution).unwrap_or(control_points);
+        }
+        if let Some(polyline) = self.to_lw_polyline() {
+            return polyline.tessellate(resolution);
+        }
+        let mut points = self.curve_vertices().map(|v| v.coord).collect::<Vec<_>>();
+        if self.flags.closed_polyline && points.len() > 2 {
+            points.push(points[0]);
+        }
+        points
+    }
+}
+
+#[test]
+fn test_tessellate() {
+    let arc = Arc {
+        circle: Circle {
+            radius: 10.0,
+            ..Default::default()
+        },
+        start_degree: 270.0,
+        end_degree: 90.0,
+    };
+    let points = arc.tessellate(&Resolution::SegmentsPerTurn(8));
+    assert_eq!(points.len(), 5);
+    assert!((points[2][0] - 10.0).abs() < 1e-9 && points[2][1].abs() < 1e-9);
+
+    let tolerance = 0.01;
+    let points = arc.tessellate(&Resolution::Tolerance(tolerance));
+    for pair in points.windows(2) {
+        let mid = super::scale(&super::add(&pair[0], &pair[1]), 0.5);
+        assert!(10.0 - super::norm(&mid) <= tolerance);
+    }
+
+    let polyline = LwPolyline {
+        vertices: vec![
+            LwPolylineVertex {
+                coord: [0.0, 0.0],
+                bulge: Some(1.0),
+                ..Default::default()
+            },
+            LwPolylineVertex {
+                coord: [2.0, 0.0],
+                ..Default::default()
+            },
+        ],
+        is_closed: true,
+        ..Default::default()
+    };
+    let points = polyline.tessellate(&Resolution::SegmentsPerTurn(4));
+    assert_eq!(points.len(), 4);
+    assert!((points[1][0] - 1.0).abs() < 1e-9 && (points[1][1] + 1.0).abs() < 1e-9);
+    assert_eq!(points[3], [0.0, 0.0, 0.0]);
+}
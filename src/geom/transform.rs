@@ -0,0 +1,942 @@
+use super::{add, cross, dot, norm, normalize, scale, sub, Matrix, Ocs, PolylineSegment};
+use crate::*;
+
+/// Applies a 3D affine transformation in place.
+pub trait Transform {
+    /// Returns `false` if the object cannot be transformed and is left unchanged.
+    fn transform(&mut self, m: &Matrix) -> bool;
+}
+
+// OCS of a planar entity before and after the transformation.
+// The new extrusion direction stays on the side the old one is mapped to,
+// so an entity mirrored within its plane gets a reversed orientation in the new OCS.
+struct OcsChange {
+    old: Ocs,
+    new: Ocs,
+    m: Matrix,
+    is_mirrored: bool,
+}
+
+impl OcsChange {
+    fn new(m: &Matrix, extrusion_direction: &[f64; 3]) -> Self {
+        let old = Ocs::new(extrusion_direction);
+        let is_mirrored = m.determinant() < 0.0;
+        let normal = m.transform_normal(&old.axes[2]);
+        let normal = if is_mirrored {
+            scale(&normal, -1.0)
+        } else {
+            normal
+        };
+        let new = Ocs::new(&normalize(&normal));
+        Self {
+            old,
+            new,
+            m: *m,
+            is_mirrored,
+        }
+    }
+
+    fn point(&self, p: &[f64; 3]) -> [f64; 3] {
+        self.new
+            .from_wcs(&self.m.transform_point(&self.old.to_wcs(p)))
+    }
+
+    // image in the new OCS of the unit vector given by an angle in the old OCS
+    fn vector(&self, degree: f64) -> [f64; 3] {
+        self.new
+            .from_wcs(&self.m.transform_vector(&self.old.direction_to_wcs(degree)))
+    }
+
+    // angle in the new OCS of a direction given by an angle in the old OCS
+    fn angle_degree(&self, degree: f64) -> f64 {
+        let v = self.vector(degree);
+        v[1].atan2(v[0]).to_degrees()
+    }
+
+    // length scale along a direction given by an angle in the old OCS
+    fn scale_at(&self, degree: f64) -> f64 {
+        norm(&self.vector(degree))
+    }
+
+    // scale of the extent along the extrusion direction (thickness)
+    fn scale_normal(&self) -> f64 {
+        dot(
+            &self.m.transform_vector(&self.old.axes[2]),
+            &self.new.axes[2],
+        )
+    }
+
+    // whether circles stay circles
+    fn is_conformal(&self) -> bool {
+        let (u, v) = (self.vector(0.0), self.vector(90.0));
+        let (nu, nv) = (norm(&u), norm(&v));
+        (nu - nv).abs() <= 1e-9 * nu.max(nv) && dot(&u, &v).abs() <= 1e-9 * nu * nv
+    }
+
+    // whether the plane of the old OCS collapses to a line or a point
+    fn is_degenerate(&self) -> bool {
+        let [x, y, _] = self.old.axes;
+        let (u, v) = (self.m.transform_vector(&x), self.m.transform_vector(&y));
+        norm(&cross(&u, &v)) == 0.0
+    }
+
+    fn extrusion_direction(&self) -> Option<[f64; 3]> {
+        if norm(&sub(&self.new.axes[2], &[0.0, 0.0, 1.0])) < 1e-12 {
+            None
+        } else {
+            Some(self.new.axes[2])
+        }
+    }
+}
+
+fn extrusion_or_z(extrusion_direction: &Option<[f64; 3]>) -> [f64; 3] {
+    extrusion_direction.unwrap_or([0.0, 0.0, 1.0])
+}
+
+// Principal semi-axes of the conjugate semi-diameters u and v, the major one first:
+// u cos(t) + v sin(t) = a cos(t - shift) + b sin(t - shift)
+fn principal_axes(u: &[f64; 3], v: &[f64; 3]) -> ([f64; 3], [f64; 3], f64) {
+    let shift = 0.5 * (2.0 * dot(u, v)).atan2(dot(u, u) - dot(v, v));
+    let (sin, cos) = shift.sin_cos();
+    let a = add(&scale(u, cos), &scale(v, sin));
+    let b = sub(&scale(v, cos), &scale(u, sin));
+    if norm(&b) > norm(&a) {
+        (b, scale(&a, -1.0), shift + 0.5 * std::f64::consts::PI)
+    } else {
+        (a, b, shift)
+    }
+}
+
+// 2D cross product of OCS vectors
+fn cross_z(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+impl Transform for Entity {
+    /// Circles and arcs are replaced by ELLIPSE entities if the transformation
+    /// does not keep them circular.
+    /// Polylines with arc segments or widths and INSERTs are left unchanged
+    /// if they can not follow such a transformation exactly.
+    /// ACIS entities and entities not supported are left unchanged.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        match self {
+            Entity::Insert(insert) => insert.transform(m),
+            Entity::Text(text) => text.transform(m),
+            Entity::MText(mtext) => mtext.transform(m),
+            Entity::Dimension(dim) => dim.transform(m),
+            Entity::Point(point) => point.transform(m),
+            Entity::Line(line) => line.transform(m),
+            Entity::Circle(circle) => {
+                if OcsChange::new(m, &extrusion_or_z(&circle.extrusion_direction)).is_conformal() {
+                    circle.transform(m)
+                } else {
+                    let mut ellipse = circle.to_ellipse();
+                    let is_transformed = ellipse.transform(m);
+                    if is_transformed {
+                        *self = Entity::Ellipse(ellipse);
+                    }
+                    is_transformed
+                }
+            }
+            Entity::Arc(arc) => {
+                let normal = extrusion_or_z(&arc.circle.extrusion_direction);
+                if OcsChange::new(m, &normal).is_conformal() {
+                    arc.transform(m)
+                } else {
+                    let mut ellipse = arc.to_ellipse();
+                    let is_transformed = ellipse.transform(m);
+                    if is_transformed {
+                        *self = Entity::Ellipse(ellipse);
+                    }
+                    is_transformed
+                }
+            }
+            Entity::Ellipse(ellipse) => ellipse.transform(m),
+            Entity::LwPolyline(polyline) => polyline.transform(m),
+            Entity::Polyline(polyline) => polyline.transform(m),
+            Entity::Spline(spline) => spline.transform(m),
+            Entity::Ray(ray) => ray.transform(m),
+            Entity::XLine(xline) => xline.transform(m),
+            Entity::Image(image) | Entity::Wipeout(image) => image.transform(m),
+            Entity::Viewport(viewport) => viewport.transform(m),
+            Entity::MLine(mline) => mline.transform(m),
+            Entity::Mesh(mesh) => mesh.transform(m),
+            Entity::Hatch(hatch) => hatch.transform(m),
+            Entity::Region(_)
+            | Entity::Solid3d(_)
+            | Entity::Body(_)
+            | Entity::Surface(_)
+            | Entity::NotSupported(..) => false,
+        }
+    }
+}
+
+impl Transform for Point {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        self.coord = m.transform_point(&self.coord);
+        self.thickness *= change.scale_normal();
+        if let Some(degree) = self.x_axis_degree {
+            self.x_axis_degree = Some(change.angle_degree(degree));
+        }
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Line {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        self.p1 = m.transform_point(&self.p1);
+        self.p2 = m.transform_point(&self.p2);
+        self.thickness *= change.scale_normal();
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Ray {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        self.base_point = m.transform_point(&self.base_point);
+        self.unit_direction = normalize(&m.transform_vector(&self.unit_direction));
+        true
+    }
+}
+
+impl Transform for XLine {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        self.base_point = m.transform_point(&self.base_point);
+        self.unit_direction = normalize(&m.transform_vector(&self.unit_direction));
+        true
+    }
+}
+
+impl Transform for Circle {
+    /// Transformations that do not keep circles circular are refused;
+    /// use [`Circle::to_ellipse`] for them.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        if !change.is_conformal() {
+            return false;
+        }
+        self.center = change.point(&self.center);
+        self.radius *= change.scale_at(0.0);
+        self.thickness *= change.scale_normal();
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Arc {
+    /// Start and end angles are swapped when mirrored, since arcs run counterclockwise.
+    /// Transformations that do not keep circles circular are refused;
+    /// use [`Arc::to_ellipse`] for them.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.circle.extrusion_direction));
+        if !change.is_conformal() {
+            return false;
+        }
+        let start = change.angle_degree(self.start_degree);
+        let end = change.angle_degree(self.end_degree);
+        if change.is_mirrored {
+            self.start_degree = end;
+            self.end_degree = start;
+        } else {
+            self.start_degree = start;
+            self.end_degree = end;
+        }
+        self.circle.transform(m);
+        true
+    }
+}
+
+impl Transform for Ellipse {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        use std::f64::consts::PI;
+        let change = OcsChange::new(m, &self.normal());
+        let u = m.transform_vector(&self.major_axis);
+        let v = m.transform_vector(&self.minor_axis());
+        let (a, mut b, shift) = principal_axes(&u, &v);
+        let span = self.end_parameter - self.start_parameter;
+        let mut start = self.start_parameter - shift;
+        if dot(&cross(&a, &b), &change.new.axes[2]) < 0.0 {
+            b = scale(&b, -1.0);
+            start = -(start + span);
+        }
+        if norm(&a) == 0.0 {
+            log::warn!("ellipse degenerated by the transformation");
+            return false;
+        }
+        self.center = m.transform_point(&self.center);
+        self.major_axis = a;
+        self.axis_ratio = norm(&b) / norm(&a);
+        if (span - 2.0 * PI).abs() > 1e-12 {
+            self.start_parameter = start.rem_euclid(2.0 * PI);
+            self.end_parameter = self.start_parameter + span;
+        }
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for LwPolyline {
+    /// Bulges are negated when mirrored.
+    /// Polylines with arc segments or widths are refused by transformations
+    /// that do not keep circles circular, since those would make them elliptic.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        let is_nonzero = |x: Option<f64>| x.is_some_and(|x| x != 0.0);
+        if !change.is_conformal()
+            && (is_nonzero(self.constant_width)
+                || self.vertices.iter().any(|v| {
+                    is_nonzero(v.bulge) || is_nonzero(v.start_width) || is_nonzero(v.end_width)
+                }))
+        {
+            return false;
+        }
+        let z = self.elevation.unwrap_or(0.0);
+        let width_scale = change.scale_at(0.0);
+        for v in &mut self.vertices {
+            let p = change.point(&[v.coord[0], v.coord[1], z]);
+            v.coord = [p[0], p[1]];
+            self.elevation = Some(p[2]);
+            v.start_width = v.start_width.map(|w| w * width_scale);
+            v.end_width = v.end_width.map(|w| w * width_scale);
+            if change.is_mirrored {
+                v.bulge = v.bulge.map(|bulge| -bulge);
+            }
+        }
+        self.constant_width = self.constant_width.map(|w| w * width_scale);
+        self.thickness = self.thickness.map(|t| t * change.scale_normal());
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Polyline {
+    /// 3D polylines and meshes transform their vertices in WCS,
+    /// while 2D polylines are transformed in the OCS like LWPOLYLINEs,
+    /// including the refusal of arc segments and widths.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        if self.is_polyline_3d() || self.is_mesh() {
+            for v in &mut self.vertices {
+                v.coord = m.transform_point(&v.coord);
+            }
+            return true;
+        }
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        let is_nonzero = |x: Option<f64>| x.is_some_and(|x| x != 0.0);
+        if !change.is_conformal()
+            && (is_nonzero(self.default_start_width)
+                || is_nonzero(self.default_end_width)
+                || self.vertices.iter().any(|v| {
+                    is_nonzero(v.bulge) || is_nonzero(v.start_width) || is_nonzero(v.end_width)
+                }))
+        {
+            return false;
+        }
+        let z = self.elevation.unwrap_or(0.0);
+        let width_scale = change.scale_at(0.0);
+        for v in &mut self.vertices {
+            v.coord = change.point(&[v.coord[0], v.coord[1], z]);
+            v.start_width = v.start_width.map(|w| w * width_scale);
+            v.end_width = v.end_width.map(|w| w * width_scale);
+            if change.is_mirrored {
+                v.bulge = v.bulge.map(|bulge| -bulge);
+            }
+            if v.flags.has_curve_fit_tangent {
+                v.curve_fit_tangent_direction = change.angle_degree(v.curve_fit_tangent_direction);
+            }
+        }
+        self.elevation = Some(change.point(&[0.0, 0.0, z])[2]);
+        self.default_start_width = self.default_start_width.map(|w| w * width_scale);
+        self.default_end_width = self.default_end_width.map(|w| w * width_scale);
+        self.thickness = self.thickness.map(|t| t * change.scale_normal());
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Spline {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        for p in self.control_points.iter_mut().chain(&mut self.fit_points) {
+            *p = m.transform_point(p);
+        }
+        for tangent in [&mut self.start_tangent, &mut self.end_tangent] {
+            *tangent = tangent.map(|t| normalize(&m.transform_vector(&t)));
+        }
+        self.normal = self.normal.map(|n| normalize(&m.transform_normal(&n)));
+        true
+    }
+}
+
+impl Transform for Hatch {
+    /// Mirroring reverses the direction of arc and ellipse edges and the bulges of
+    /// polyline paths. If the transformation does not keep circles circular,
+    /// circular arc edges become elliptic arcs and polyline paths with bulges become edges.
+    /// Transformations that collapse the plane of the hatch are refused.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        if change.is_degenerate() {
+            log::warn!("hatch degenerated by the transformation");
+            return false;
+        }
+        let is_conformal = change.is_conformal();
+        let z = self.elevation_point[2];
+        let point = |p: &[f64; 2]| {
+            let p = change.point(&[p[0], p[1], z]);
+            [p[0], p[1]]
+        };
+        let vector = |v: &[f64; 2]| {
+            let (p, q) = (point(&[0.0, 0.0]), point(v));
+            [q[0] - p[0], q[1] - p[1]]
+        };
+        // new start and end of an arc edge, keeping its sweep
+        let arc_angles = |start: f64, end: f64, is_counterclockwise: bool| {
+            let sign = if is_counterclockwise { 1.0 } else { -1.0 };
+            let new_sign = if is_counterclockwise != change.is_mirrored {
+                1.0
+            } else {
+                -1.0
+            };
+            let new_start = new_sign * change.angle_degree(sign * start);
+            (new_start, new_start + end - start)
+        };
+        let length_scale = change.scale_at(0.0);
+        for path in &mut self.boundary_paths {
+            if let HatchBoundary::Polyline(polyline) = &path.boundary {
+                if !is_conformal
+                    && polyline
+                        .vertices
+                        .iter()
+                        .any(|v| v.bulge.unwrap_or(0.0) != 0.0)
+                {
+                    path.boundary = HatchBoundary::Edges(polyline_edges(polyline));
+                }
+            }
+            match &mut path.boundary {
+                HatchBoundary::Polyline(polyline) => {
+                    for v in &mut polyline.vertices {
+                        v.coord = point(&v.coord);
+                        if change.is_mirrored {
+                            v.bulge = v.bulge.map(|bulge| -bulge);
+                        }
+                    }
+                }
+                HatchBoundary::Edges(edges) => {
+                    for edge in edges {
+                        if let HatchEdge::CircularArc {
+                            center,
+                            radius,
+                            start_degree,
+                            end_degree,
+                            is_counterclockwise,
+                        } = *edge
+                        {
+                            if !is_conformal {
+                                *edge = HatchEdge::EllipticArc {
+                                    center,
+                                    major_axis: [radius, 0.0],
+                                    axis_ratio: 1.0,
+                                    start_degree,
+                                    end_degree,
+                                    is_counterclockwise,
+                                };
+                            }
+                        }
+                        match edge {
+                            HatchEdge::Line { start, end } => {
+                                *start = point(start);
+                                *end = point(end);
+                            }
+                            HatchEdge::CircularArc {
+                                center,
+                                radius,
+                                start_degree,
+                                end_degree,
+                                is_counterclockwise,
+                            } => {
+                                *center = point(center);
+                                *radius *= length_scale;
+                                let (start, end) =
+                                    arc_angles(*start_degree, *end_degree, *is_counterclockwise);
+                                *start_degree = start;
+                                *end_degree = end;
+                                *is_counterclockwise ^= change.is_mirrored;
+                            }
+                            // the edge runs through the parameters sign * (start + t)
+                            HatchEdge::EllipticArc {
+                                center,
+                                major_axis,
+                                axis_ratio,
+                                start_degree,
+                                end_degree,
+                                is_counterclockwise,
+                            } => {
+                                let minor_axis =
+                                    [-major_axis[1] * *axis_ratio, major_axis[0] * *axis_ratio];
+                                let [u, v] = [*major_axis, minor_axis].map(|axis| {
+                                    let [x, y] = vector(&axis);
+                                    [x, y, 0.0]
+                                });
+                                let (a, mut b, shift) = principal_axes(&u, &v);
+                                *center = point(center);
+                                // only a zero major axis, kept as is since the plane does not collapse
+                                if norm(&a) == 0.0 {
+                                    continue;
+                                }
+                                let sign = if *is_counterclockwise { 1.0 } else { -1.0 };
+                                // keep the minor axis counterclockwise from the major one
+                                if cross_z(&a, &b) < 0.0 {
+                                    b = scale(&b, -1.0);
+                                    *is_counterclockwise = !*is_counterclockwise;
+                                }
+                                let span = *end_degree - *start_degree;
+                                *start_degree =
+                                    (*start_degree - sign * shift.to_degrees()).rem_euclid(360.0);
+                                *end_degree = *start_degree + span;
+                                *major_axis = [a[0], a[1]];
+                                *axis_ratio = norm(&b) / norm(&a);
+                            }
+                            HatchEdge::Spline(spline) => {
+                                for p in spline
+                                    .control_points
+                                    .iter_mut()
+                                    .chain(&mut spline.fit_points)
+                                {
+                                    *p = point(p);
+                                }
+                                for tangent in [&mut spline.start_tangent, &mut spline.end_tangent]
+                                {
+                                    *tangent = tangent.map(|t| vector(&t));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for line in &mut self.pattern_lines {
+            let dash_scale = change.scale_at(line.angle_degree);
+            line.base_point = point(&line.base_point);
+            line.offset = vector(&line.offset);
+            line.angle_degree = change.angle_degree(line.angle_degree);
+            for length in &mut line.dash_lengths {
+                *length *= dash_scale;
+            }
+        }
+        self.pattern_angle_degree = change.angle_degree(self.pattern_angle_degree);
+        self.pattern_scale *= length_scale;
+        for p in &mut self.seed_points {
+            *p = point(p);
+        }
+        self.elevation_point = [0.0, 0.0, change.point(&[0.0, 0.0, z])[2]];
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+// edges of a closed polyline path
+fn polyline_edges(polyline: &LwPolyline) -> Vec<HatchEdge> {
+    let polyline = LwPolyline {
+        vertices: polyline.vertices.clone(),
+        is_closed: true,
+        ..Default::default()
+    };
+    polyline
+        .segments()
+        .into_iter()
+        .map(|segment| match segment {
+            PolylineSegment::Line { start, end } => HatchEdge::Line { start, end },
+            PolylineSegment::Arc {
+                center,
+                radius,
+                start_radian,
+                sweep_radian,
+                ..
+            } => {
+                let is_counterclockwise = sweep_radian > 0.0;
+                let start_degree = if is_counterclockwise {
+                    start_radian.to_degrees()
+                } else {
+                    -start_radian.to_degrees()
+                };
+                HatchEdge::CircularArc {
+                    center,
+                    radius,
+                    start_degree,
+                    end_degree: start_degree + sweep_radian.abs().to_degrees(),
+                    is_counterclockwise,
+                }
+            }
+        })
+        .collect()
+}
+
+impl Transform for Text {
+    /// Mirrored text is drawn backward (mirror flag X) from the same alignment points.
+    /// Scaling updates the height, the width factor and the oblique angle.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_vector));
+        let rotation = self.rotation_degree.unwrap_or(0.0);
+        let mut flags = self.mirror_flags.unwrap_or_default();
+        // slant of the glyphs' vertical strokes along the text direction
+        let slant = |flags: &TextMirrorFlags, tan: f64| if flags.x { -tan } else { tan };
+        let tan = slant(
+            &flags,
+            self.oblique_degree.unwrap_or(0.0).to_radians().tan(),
+        );
+        let (ex, ey) = (change.vector(rotation), change.vector(rotation + 90.0));
+        let x = if change.is_mirrored {
+            flags.x = !flags.x;
+            scale(&ex, -1.0)
+        } else {
+            ex
+        };
+        let x_scale = norm(&x);
+        let x_unit = scale(&x, 1.0 / x_scale);
+        let height_scale = cross_z(&x_unit, &ey).abs();
+        let vertical = add(&ey, &scale(&ex, tan));
+        let tan = slant(&flags, dot(&vertical, &x_unit) / height_scale);
+
+        self.point1 = change.point(&self.point1);
+        self.point2 = change.point(&self.point2);
+        self.height *= height_scale;
+        self.rotation_degree = Some(x[1].atan2(x[0]).to_degrees());
+        if self.relative_x_scale_factor.is_some() || (x_scale - height_scale).abs() > 1e-12 {
+            let factor = self.relative_x_scale_factor.unwrap_or(1.0);
+            self.relative_x_scale_factor = Some(factor * x_scale / height_scale);
+        }
+        if self.oblique_degree.is_some() || tan.abs() > 1e-12 {
+            self.oblique_degree = Some(tan.atan().to_degrees());
+        }
+        if self.mirror_flags.is_some() || flags.x {
+            self.mirror_flags = Some(flags);
+        }
+        self.thickness = self.thickness.map(|t| t * change.scale_normal());
+        self.extrusion_vector = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for MText {
+    /// MTEXT can not be mirrored: the text direction follows the transformation
+    /// and the text stays readable.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_vector));
+        let x_axis = match self.x_axis {
+            Some(x_axis) => x_axis,
+            None => change
+                .old
+                .direction_to_wcs(self.rotation_radian.unwrap_or(0.0).to_degrees()),
+        };
+        let y_axis = cross(&change.old.axes[2], &normalize(&x_axis));
+        let x_axis = m.transform_vector(&x_axis);
+        let y_axis = m.transform_vector(&y_axis);
+        let scale_x = norm(&x_axis);
+        let x_unit = normalize(&x_axis);
+        let scale_y = norm(&cross(&x_unit, &y_axis));
+        self.point = m.transform_point(&self.point);
+        self.x_axis = Some(x_unit);
+        self.rotation_radian = None;
+        self.height *= scale_y;
+        self.character_height *= scale_y;
+        self.rectangle_width *= scale_x;
+        self.character_width *= scale_x;
+        self.extrusion_vector = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Insert {
+    /// A mirrored reference gets a negative Y scale factor.
+    /// Transformations that skew the block axes can not be represented and are refused.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &self.extrusion_direction);
+        let rotation = self.rotation_degree;
+        let (ex, ey) = (change.vector(rotation), change.vector(rotation + 90.0));
+        if dot(&ex, &ey).abs() > 1e-9 * norm(&ex) * norm(&ey) {
+            return false;
+        }
+        let x_scale = norm(&ex);
+        let y_scale = cross_z(&ex, &ey) / x_scale;
+        self.insertion_point = change.point(&self.insertion_point);
+        self.scale_factor[0] *= x_scale;
+        self.scale_factor[1] *= y_scale;
+        self.scale_factor[2] *= change.scale_normal();
+        self.column_spacing *= x_scale;
+        self.row_spacing *= y_scale;
+        self.rotation_degree = ex[1].atan2(ex[0]).to_degrees();
+        self.extrusion_direction = change.new.axes[2];
+        true
+    }
+}
+
+impl Transform for Dimension {
+    /// Updates the definition points and angles;
+    /// the anonymous block named by `block_name` is not changed.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        let wcs_point = |p: &mut [f64; 3]| *p = m.transform_point(p);
+        let ocs_point = |p: &mut [f64; 3]| *p = change.point(p);
+        let angle = |degree: &mut f64| *degree = change.angle_degree(*degree);
+        wcs_point(&mut self.definition_point);
+        self.definition_point2.as_mut().map(wcs_point);
+        self.definition_point3.as_mut().map(wcs_point);
+        self.definition_point4.as_mut().map(wcs_point);
+        ocs_point(&mut self.text_mid_point);
+        self.insertion_point.as_mut().map(ocs_point);
+        self.arc_location.as_mut().map(ocs_point);
+        self.rotation_angle.as_mut().map(angle);
+        self.text_rotation_angle.as_mut().map(angle);
+        self.horizontal_direction_angle.as_mut().map(angle);
+        self.leader_length = self.leader_length.map(|l| l * change.scale_at(0.0));
+        self.extrusion_direction = change.extrusion_direction();
+        if self.actual_measurement.is_some() {
+            self.actual_measurement = Some(self.measurement());
+        }
+        true
+    }
+}
+
+impl Transform for Image {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        self.insertion_point = m.transform_point(&self.insertion_point);
+        self.u_vector = m.transform_vector(&self.u_vector);
+        self.v_vector = m.transform_vector(&self.v_vector);
+        true
+    }
+}
+
+impl Transform for ViewportEntity {
+    /// Moves and resizes the viewport in paper space; the model space view is not changed.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        self.center = m.transform_point(&self.center);
+        self.width *= norm(&m.transform_vector(&[1.0, 0.0, 0.0]));
+        self.height *= norm(&m.transform_vector(&[0.0, 1.0, 0.0]));
+        true
+    }
+}
+
+impl Transform for MLine {
+    /// Element offsets follow the scale along the OCS X axis.
+    /// The scale factor is negated if the MLINE is mirrored.
+    fn transform(&mut self, m: &Matrix) -> bool {
+        let change = OcsChange::new(m, &extrusion_or_z(&self.extrusion_direction));
+        let offset_scale = change.scale_at(0.0);
+        self.start_point = m.transform_point(&self.start_point);
+        for vertex in &mut self.vertices {
+            vertex.coord = m.transform_point(&vertex.coord);
+            vertex.direction = normalize(&m.transform_vector(&vertex.direction));
+            vertex.miter_direction = normalize(&m.transform_vector(&vertex.miter_direction));
+            for element in &mut vertex.elements {
+                for parameter in element
+                    .parameters
+                    .iter_mut()
+                    .chain(element.area_fill_parameters.iter_mut())
+                {
+                    *parameter *= offset_scale;
+                }
+            }
+        }
+        // a negative scale puts the elements on the other side of the vertices
+        self.scale_factor *= if change.is_mirrored {
+            -offset_scale
+        } else {
+            offset_scale
+        };
+        self.extrusion_direction = change.extrusion_direction();
+        true
+    }
+}
+
+impl Transform for Mesh {
+    fn transform(&mut self, m: &Matrix) -> bool {
+        for vertex in &mut self.vertices {
+            *vertex = m.transform_point(vertex);
+        }
+        true
+    }
+}
+
+#[test]
+fn test_transform_arc() {
+    let mut arc = Arc {
+        circle: Circle {
+            center: [1.0, 0.0, 0.0],
+            radius: 1.0,
+            ..Default::default()
+        },
+        start_degree: 0.0,
+        end_degree: 90.0,
+    };
+    let m = Matrix::rotation_z(90.0).then(&Matrix::scaling([2.0, 2.0, 2.0]));
+    arc.transform(&m);
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    assert!(close(arc.circle.center[0], 0.0) && close(arc.circle.center[1], 2.0));
+    assert!(close(arc.circle.radius, 2.0));
+    assert!(close(arc.start_degree, 90.0) && close(arc.end_degree, 180.0));
+    let start = arc.start_point_wcs();
+    assert!(close(start[0], 0.0) && close(start[1], 4.0));
+}
+
+#[test]
+fn test_transform_mirror() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    let mirror = Matrix::scaling([-1.0, 1.0, 1.0]);
+
+    let mut arc = Entity::Arc(Arc {
+        circle: Circle {
+            center: [1.0, 0.0, 0.0],
+            radius: 1.0,
+            ..Default::default()
+        },
+        start_degree: 0.0,
+        end_degree: 90.0,
+    });
+    arc.transform(&mirror);
+    let arc = match arc {
+        Entity::Arc(arc) => arc,
+        _ => panic!("not an arc"),
+    };
+    assert_eq!(arc.circle.extrusion_direction, None);
+    assert!(close(arc.start_degree, 90.0) && close(arc.end_degree, 180.0));
+    let start = arc.start_point_wcs();
+    assert!(close(start[0], -1.0) && close(start[1], 1.0));
+
+    let mut text = Text {
+        height: 2.0,
+        oblique_degree: Some(15.0),
+        ..Default::default()
+    };
+    text.transform(&mirror);
+    assert!(text.mirror_flags.unwrap().x);
+    assert!(close(text.rotation_degree.unwrap(), 0.0));
+    assert!(close(text.height, 2.0) && close(text.oblique_degree.unwrap(), 15.0));
+
+    let mut circle = Entity::Circle(Circle {
+        radius: 1.0,
+        ..Default::default()
+    });
+    circle.transform(&Matrix::scaling([1.0, 3.0, 1.0]));
+    match circle {
+        Entity::Ellipse(ellipse) => {
+            assert!(close(ellipse.major_axis[0], 0.0) && close(ellipse.major_axis[1], 3.0));
+            assert!(close(ellipse.axis_ratio, 1.0 / 3.0));
+            let minor = ellipse.minor_axis();
+            assert!(close(minor[0], -1.0) && close(minor[1], 0.0));
+        }
+        _ => panic!("not an ellipse"),
+    }
+
+    let stretch = Matrix::scaling([1.0, 3.0, 1.0]);
+    let mut bulged = LwPolyline {
+        vertices: vec![
+            LwPolylineVertex {
+                bulge: Some(1.0),
+                ..Default::default()
+            },
+            LwPolylineVertex {
+                coord: [2.0, 0.0],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    assert!(!bulged.transform(&stretch));
+    assert_eq!(bulged.vertices[1].coord, [2.0, 0.0]);
+    bulged.vertices[0].bulge = None;
+    assert!(bulged.transform(&stretch));
+    assert!(!Circle::default().transform(&stretch));
+
+    let skew = Matrix::from_axes([1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0; 3]);
+    let mut insert = Insert::default();
+    assert!(!insert.transform(&skew));
+    assert!(insert.transform(&stretch));
+    assert_eq!(insert.scale_factor, [1.0, 3.0, 1.0]);
+
+    let mut mline = MLine {
+        vertices: vec![MLineVertex {
+            direction: [1.0, 0.0, 0.0],
+            miter_direction: [0.0, 1.0, 0.0],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert!(mline.transform(&Matrix::scaling([1.0, -2.0, 1.0])));
+    assert!(close(mline.scale_factor, -1.0));
+    assert_eq!(mline.vertices[0].miter_direction, [0.0, -1.0, 0.0]);
+
+    let mut table = Entity::NotSupported("ACAD_TABLE".to_owned(), Vec::new());
+    assert!(!table.transform(&mirror));
+}
+
+#[test]
+fn test_transform_hatch() {
+    let resolution = Resolution::SegmentsPerTurn(36);
+    let arc = HatchEdge::CircularArc {
+        center: [0.0, 0.0],
+        radius: 1.0,
+        start_degree: 30.0,
+        end_degree: 300.0,
+        is_counterclockwise: false,
+    };
+    let line = HatchEdge::Line {
+        start: [0.5, 0.75f64.sqrt()],
+        end: [0.0, 0.0],
+    };
+    let mut hatch = Hatch {
+        boundary_paths: vec![
+            HatchBoundaryPath {
+                boundary: HatchBoundary::Edges(vec![arc, line]),
+                ..Default::default()
+            },
+            HatchBoundaryPath {
+                boundary: HatchBoundary::Polyline(LwPolyline {
+                    vertices: vec![
+                        LwPolylineVertex {
+                            coord: [3.0, 0.0],
+                            bulge: Some(1.0),
+                            ..Default::default()
+                        },
+                        LwPolylineVertex {
+                            coord: [5.0, 0.0],
+                            bulge: Some(-0.5),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let m = Matrix::scaling([2.0, -1.0, 1.0]).then(&Matrix::rotation_z(30.0));
+    let expected = hatch
+        .boundary_paths
+        .iter()
+        .map(|path| {
+            path.tessellate(&resolution)
+                .iter()
+                .map(|p| m.transform_point(&[p[0], p[1], 0.0]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert!(hatch.transform(&m));
+    assert!(matches!(
+        &hatch.boundary_paths[1].boundary,
+        HatchBoundary::Edges(edges) if matches!(edges[0], HatchEdge::EllipticArc { .. })
+    ));
+    for (path, expected) in hatch.boundary_paths.iter().zip(&expected) {
+        let points = path.tessellate(&resolution);
+        assert_eq!(points.len(), expected.len());
+        for (p, q) in points.iter().zip(expected) {
+            assert!((p[0] - q[0]).abs() < 1e-9 && (p[1] - q[1]).abs() < 1e-9);
+        }
+    }
+    // a projection onto a line collapses the hatch
+    let transformed = hatch.clone();
+    assert!(!hatch.transform(&Matrix::scaling([1.0, 0.0, 1.0])));
+    assert_eq!(hatch, transformed);
+}
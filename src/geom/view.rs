@@ -0,0 +1,109 @@
+use super::{add, arbitrary_axes, scale};
+use crate::*;
+
+// corners of a view window given in DCS, mapped to WCS
+fn window_corners(
+    center: [f64; 2],
+    width: f64,
+    height: f64,
+    direction: &[f64; 3],
+    target: &[f64; 3],
+    twist_degree: f64,
+) -> [[f64; 3]; 4] {
+    let [ax, ay, _] = arbitrary_axes(direction);
+    let (sin, cos) = twist_degree.to_radians().sin_cos();
+    let (w, h) = (width / 2.0, height / 2.0);
+    [[-w, -h], [w, -h], [w, h], [-w, h]].map(|[dx, dy]| {
+        let (x, y) = (center[0] + dx, center[1] + dy);
+        // undo the twist rotation of DCS
+        let (x, y) = (cos * x + sin * y, -sin * x + cos * y);
+        add(target, &add(&scale(&ax, x), &scale(&ay, y)))
+    })
+}
+
+impl Viewport {
+    /// Corners of the displayed area in WCS, counterclockwise from the lower left.
+    /// Perspective views are treated as parallel projections.
+    pub fn view_corners(&self) -> [[f64; 3]; 4] {
+        window_corners(
+            self.view_center,
+            self.view_height * self.aspect_ratio,
+            self.view_height,
+            &self.view_direction,
+            &self.view_target,
+            self.twist_degree,
+        )
+    }
+}
+
+impl View {
+    /// Corners of the view in WCS, counterclockwise from the lower left.
+    /// Perspective views are treated as parallel projections.
+    pub fn view_corners(&self) -> [[f64; 3]; 4] {
+        window_corners(
+            self.center,
+            self.width,
+            self.height,
+            &self.view_direction,
+            &self.target,
+            self.twist_degree,
+        )
+    }
+}
+
+impl Document {
+    /// The "*ACTIVE" VPORT table record.
+    /// If the model space is split into several tiled viewports, the first one is returned.
+    pub fn active_viewport(&self) -> Option<&Viewport> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find_map(|entry| match &entry.record {
+                TableRecord::Viewport(vport) if entry.name.eq_ignore_ascii_case("*ACTIVE") => {
+                    Some(vport)
+                }
+                _ => None,
+            })
+    }
+
+    /// Minimum and maximum WCS coordinates of the area a CAD package shows
+    /// when it opens the document, taken from the active viewport.
+    pub fn initial_view(&self) -> Option<[[f64; 3]; 2]> {
+        let corners = self.active_viewport()?.view_corners();
+        let mut min = corners[0];
+        let mut max = corners[0];
+        for p in &corners[1..] {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        Some([min, max])
+    }
+}
+
+#[test]
+fn test_initial_view() {
+    let viewport = Viewport {
+        view_center: [50.0, 20.0],
+        view_height: 40.0,
+        aspect_ratio: 2.0,
+        view_target: [100.0, 0.0, 0.0],
+        ..Default::default()
+    };
+    let doc = Document {
+        tables: vec![TableNode {
+            handle: 8,
+            entries: vec![TableEntry {
+                handle: 0x29,
+                name: "*Active".to_owned(),
+                record: TableRecord::Viewport(viewport),
+            }],
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        doc.initial_view(),
+        Some([[110.0, 0.0, 0.0], [190.0, 40.0, 0.0]])
+    );
+}
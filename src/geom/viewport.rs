@@ -0,0 +1,60 @@
+use crate::ViewportEntity;
+
+impl ViewportEntity {
+    /// Paper space units per model space unit.
+    pub fn scale(&self) -> f64 {
+        if self.view_height == 0.0 {
+            1.0
+        } else {
+            self.height / self.view_height
+        }
+    }
+
+    /// Maps a model space point (WCS) to paper space as displayed through this viewport.
+    /// Perspective views are treated as parallel projections.
+    pub fn model_to_paper(&self, p: &[f64; 3]) -> [f64; 3] {
+        let [ax, ay, _] = super::arbitrary_axes(&self.view_direction);
+        let q = super::sub(p, &self.view_target);
+        let (x, y) = (super::dot(&q, &ax), super::dot(&q, &ay));
+        let (sin, cos) = self.twist_degree.to_radians().sin_cos();
+        let (x, y) = (cos * x - sin * y, sin * x + cos * y);
+        let scale = self.scale();
+        [
+            self.center[0] + scale * (x - self.view_center[0]),
+            self.center[1] + scale * (y - self.view_center[1]),
+            self.center[2],
+        ]
+    }
+
+    /// Corners of the viewport frame in paper space.
+    pub fn frame(&self) -> [[f64; 3]; 4] {
+        let [cx, cy, cz] = self.center;
+        let (w, h) = (self.width / 2.0, self.height / 2.0);
+        [
+            [cx - w, cy - h, cz],
+            [cx + w, cy - h, cz],
+            [cx + w, cy + h, cz],
+            [cx - w, cy + h, cz],
+        ]
+    }
+}
+
+#[test]
+fn test_model_to_paper() {
+    let viewport = ViewportEntity {
+        center: [100.0, 50.0, 0.0],
+        width: 200.0,
+        height: 100.0,
+        view_center: [10.0, 20.0],
+        view_height: 1000.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        viewport.model_to_paper(&[10.0, 20.0, 0.0]),
+        [100.0, 50.0, 0.0]
+    );
+    assert_eq!(
+        viewport.model_to_paper(&[510.0, 20.0, 0.0]),
+        [150.0, 50.0, 0.0]
+    );
+}
@@ -0,0 +1,281 @@
+use crate::*;
+
+/// Layer properties overridden in a paper space viewport (VPLAYER overrides).
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LayerViewportOverride {
+    pub viewport_handle: u32,          // 335
+    pub color_number: Option<u8>,      // 420 (AcCmColor with ACI)
+    pub color_rgb: Option<Rgb>,        // 420 (AcCmColor with true color)
+    pub line_type_handle: Option<u32>, // 343
+    pub line_weight: Option<i16>,      // 91
+    pub transparency: Option<i32>,     // 440
+}
+
+impl Document {
+    /// Finds a LAYER table record by name (case-insensitive).
+    pub fn layer(&self, name: &str) -> Option<&Layer> {
+        self.layer_entry(name)
+            .and_then(|entry| match &entry.record {
+                TableRecord::Layer(layer) => Some(layer),
+                _ => None,
+            })
+    }
+
+    fn layer_entry(&self, name: &str) -> Option<&TableEntry> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find(|entry| {
+                matches!(entry.record, TableRecord::Layer(_))
+                    && entry.name.eq_ignore_ascii_case(name)
+            })
+    }
+
+    /// Per-viewport overrides stored in XRECORDs of the layer's extension dictionary.
+    pub fn layer_viewport_overrides(&self, layer: &Layer) -> Vec<LayerViewportOverride> {
+        let dictionary = match layer
+            .extension_dictionary_handle
+            .and_then(|handle| self.find_object(handle))
+        {
+            Some(ObjectNode {
+                object: Object::Dictionary(dictionary),
+                ..
+            }) => dictionary,
+            _ => return Vec::new(),
+        };
+        let mut overrides: Vec<LayerViewportOverride> = Vec::new();
+        for &(_, handle) in &dictionary.entries {
+            let xrecord = match self.find_object(handle) {
+                Some(ObjectNode {
+                    object: Object::XRecord(xrecord),
+                    ..
+                }) => xrecord,
+                _ => continue,
+            };
+            let mut current: Option<usize> = None;
+            for atom in &xrecord.data {
+                let value = &atom.value;
+                if atom.code == 335 {
+                    current = u32::from_str_radix(value, 16).ok().map(|vp| {
+                        match overrides.iter().position(|o| o.viewport_handle == vp) {
+                            Some(i) => i,
+                            None => {
+                                overrides.push(LayerViewportOverride {
+                                    viewport_handle: vp,
+                                    ..Default::default()
+                                });
+                                overrides.len() - 1
+                            }
+                        }
+                    });
+                    continue;
+                }
+                let target = match current {
+                    Some(i) => &mut overrides[i],
+                    None => continue,
+                };
+                match atom.code {
+                    420 => {
+                        // AcCmColor: the high byte is the color method
+                        if let Ok(raw) = value.parse::<i32>() {
+                            match (raw >> 24) & 0xff {
+                                0xc2 => {
+                                    target.color_rgb = Some(Rgb {
+                                        r: (raw >> 16) as u8,
+                                        g: (raw >> 8) as u8,
+                                        b: raw as u8,
+                                    })
+                                }
+                                0xc3 => target.color_number = Some(raw as u8),
+                                _ => {}
+                            }
+                        }
+                    }
+                    343 => target.line_type_handle = u32::from_str_radix(value, 16).ok(),
+                    91 => target.line_weight = value.parse().ok(),
+                    440 => target.transparency = value.parse().ok(),
+                    102 if value.starts_with('}') => current = None,
+                    _ => {}
+                }
+            }
+        }
+        overrides
+    }
+
+    /// The layer as displayed in the paper space viewport `viewport_handle`,
+    /// with viewport overrides applied and frozen if the viewport freezes it.
+    pub fn layer_in_viewport(&self, name: &str, viewport_handle: u32) -> Option<Layer> {
+        let entry = self.layer_entry(name)?;
+        let mut layer = match &entry.record {
+            TableRecord::Layer(layer) => layer.clone(),
+            _ => return None,
+        };
+        for o in self.layer_viewport_overrides(&layer) {
+            if o.viewport_handle != viewport_handle {
+                continue;
+            }
+            if let Some(color_number) = o.color_number {
+                layer.color_index = color_number;
+                if !layer.is_off {
+                    layer.color_number = Some(color_number);
+                }
+                layer.color_rgb = None;
+            }
+            if o.color_rgb.is_some() {
+                layer.color_rgb = o.color_rgb;
+            }
+            if let Some(entry) = o.line_type_handle.and_then(|h| self.find_table_entry(h)) {
+                layer.line_type = Some(entry.name.clone());
+            }
+            if o.line_weight.is_some() {
+                layer.line_weight = o.line_weight;
+            }
+            if o.transparency.is_some() {
+                layer.transparency = o.transparency;
+            }
+        }
+        let viewport = self
+            .entities
+            .iter()
+            .chain(self.blocks.iter().flat_map(|block| block.entities.iter()))
+            .find_map(|node| match &node.entity {
+                Entity::Viewport(viewport) if node.header.handle == viewport_handle => {
+                    Some(viewport)
+                }
+                _ => None,
+            });
+        if viewport.is_some_and(|vp| vp.frozen_layer_handles.contains(&entry.handle)) {
+            layer.flags.is_frozen = true;
+        }
+        Some(layer)
+    }
+}
+
+#[test]
+fn test_layer_in_viewport() {
+    let dxf = "  0
+SECTION
+  2
+TABLES
+  0
+TABLE
+  2
+LAYER
+  5
+2
+  0
+LAYER
+  5
+10
+102
+{ACAD_XDICTIONARY
+360
+A0
+102
+}
+330
+2
+100
+AcDbSymbolTableRecord
+100
+AcDbLayerTableRecord
+  2
+Walls
+ 70
+4
+ 62
+-1
+420
+16711680
+  6
+Continuous
+1001
+AcCmTransparency
+1071
+33554559
+  0
+ENDTAB
+  0
+ENDSEC
+  0
+SECTION
+  2
+ENTITIES
+  0
+VIEWPORT
+  5
+50
+  8
+0
+ 67
+1
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 40
+100.0
+ 41
+50.0
+ 69
+2
+331
+10
+  0
+ENDSEC
+  0
+SECTION
+  2
+OBJECTS
+  0
+DICTIONARY
+  5
+A0
+330
+10
+100
+AcDbDictionary
+280
+1
+  3
+ADSK_XREC_LAYER_COLOR_OVR
+360
+A1
+  0
+XRECORD
+  5
+A1
+330
+A0
+100
+AcDbXrecord
+280
+1
+102
+{ADSK_LYR_COLOR_OVERRIDE
+335
+50
+420
+-1023410174
+102
+}
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let layer = doc.layer("walls").unwrap();
+    assert!(layer.is_off && layer.flags.is_locked && !layer.flags.is_frozen);
+    assert_eq!((layer.color_number, layer.color_index), (None, 1));
+    assert_eq!(layer.color_rgb, Some(Rgb { r: 255, g: 0, b: 0 }));
+    assert_eq!(layer.transparency, Some(0x0200007f));
+    let overrides = doc.layer_viewport_overrides(layer);
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].color_number, Some(2));
+    let layer = doc.layer_in_viewport("Walls", 0x50).unwrap();
+    assert_eq!((layer.color_number, layer.color_index), (None, 2));
+    assert_eq!(layer.color_rgb, None);
+    assert!(layer.flags.is_frozen && !layer.is_visible());
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+/// A LAYOUT object together with the document it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutRef<'a> {
+    pub doc: &'a Document,
+    pub handle: u32,
+    pub layout: &'a Layout,
+}
+
+impl<'a> LayoutRef<'a> {
+    /// Name of the block that holds the content of this layout
+    /// ("*Model_Space", "*Paper_Space", "*Paper_Space0", ...).
+    pub fn block_name(&self) -> Option<&'a str> {
+        self.doc
+            .find_table_entry(self.layout.block_record_handle)
+            .map(|entry| entry.name.as_str())
+    }
+
+    pub fn is_model_space(&self) -> bool {
+        self.block_name()
+            .is_some_and(|name| name.eq_ignore_ascii_case("*Model_Space"))
+    }
+
+    /// Entities of this layout.
+    /// Those of model space and of the active paper space layout are stored in the ENTITIES section,
+    /// while the other layouts have their entities in the corresponding block definitions.
+    pub fn entities(&self) -> Vec<&'a EntityNode> {
+        let block_name = match self.block_name() {
+            Some(name) => name,
+            None => return Vec::new(),
+        };
+        let space = if block_name.eq_ignore_ascii_case("*Model_Space") {
+            Some(Space::ModelSpace)
+        } else if block_name.eq_ignore_ascii_case("*Paper_Space") {
+            Some(Space::PaperSpace)
+        } else {
+            None
+        };
+        let entities = self
+            .doc
+            .entities
+            .iter()
+            .filter(move |entity| Some(entity.header.space) == space);
+        let block_entities = self
+            .doc
+            .blocks
+            .iter()
+            .filter(|block| block.block_name.eq_ignore_ascii_case(block_name))
+            .flat_map(|block| block.entities.iter());
+        entities.chain(block_entities).collect()
+    }
+
+    /// VIEWPORT entities of this layout, including the paper space viewport itself (id = 1).
+    pub fn viewports(&self) -> Vec<(&'a EntityHeader, &'a ViewportEntity)> {
+        self.entities()
+            .into_iter()
+            .filter_map(|entity| match &entity.entity {
+                Entity::Viewport(viewport) => Some((&entity.header, viewport.as_ref())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Document {
+    /// Layouts sorted by tab order (model space comes first).
+    pub fn layouts(&self) -> Vec<LayoutRef<'_>> {
+        let mut layouts = self
+            .objects
+            .iter()
+            .filter_map(|node| match &node.object {
+                Object::Layout(layout) => Some(LayoutRef {
+                    doc: self,
+                    handle: node.header.handle,
+                    layout,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        layouts.sort_by_key(|layout| layout.layout.tab_order);
+        layouts
+    }
+
+    pub fn layout(&self, name: &str) -> Option<LayoutRef<'_>> {
+        self.layouts()
+            .into_iter()
+            .find(|layout| layout.layout.name == name)
+    }
+
+    /// Names of the layers frozen in the viewport.
+    pub fn frozen_layer_names(&self, viewport: &ViewportEntity) -> Vec<&str> {
+        viewport
+            .frozen_layer_handles
+            .iter()
+            .filter_map(|&handle| self.find_table_entry(handle))
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+}
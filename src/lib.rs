@@ -9,6 +9,26 @@ pub use atom::Atom;
 mod node;
 pub use node::Node;
 
+mod color;
+
+mod geom;
+pub use geom::{
+    BoundingBox, Contour, ContourGap, Contours, Dash, DimensionGraphics, Matrix, Ocs,
+    PolylineSegment, Resolution, SpatialIndex, Tessellate, Transform,
+};
+
+mod layer;
+pub use layer::LayerViewportOverride;
+
+mod layout;
+pub use layout::LayoutRef;
+
+mod properties;
+pub use properties::EffectiveProperties;
+
+mod text_style;
+pub use text_style::TextFont;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
     #[error(transparent)]
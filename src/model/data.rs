@@ -1,14 +1,10 @@
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum LineTypeRef {
+    #[default]
     ByLayer,
     ByBlock,
     ByName(String),
 }
-impl Default for LineTypeRef {
-    fn default() -> Self {
-        Self::ByLayer
-    }
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ShadowMode {
@@ -18,19 +14,15 @@ pub enum ShadowMode {
     IgnoresShadows,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum ColorNumber {
+    #[default]
     ByLayer,
     ByEntity,
     ByBlock,
     TurnedOff,
     Number(u8),
 }
-impl Default for ColorNumber {
-    fn default() -> Self {
-        Self::ByLayer
-    }
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Rgb {
@@ -39,19 +31,16 @@ pub struct Rgb {
     pub b: u8,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Space {
+    #[default]
     ModelSpace,
     PaperSpace,
 }
-impl Default for Space {
-    fn default() -> Self {
-        Self::ModelSpace
-    }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum DimensionType {
+    #[default]
     RotatedOrHorizontalOrVertical,
     Aligned,
     Angular,
@@ -60,11 +49,6 @@ pub enum DimensionType {
     Angular3Point,
     Ordinate(OrdinateType),
 }
-impl Default for DimensionType {
-    fn default() -> Self {
-        Self::RotatedOrHorizontalOrVertical
-    }
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum OrdinateType {
@@ -78,8 +62,9 @@ pub struct DimensionFlags {
     pub dimension_text_is_positioned_at_user_defined_location: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum AttachmentPoint {
+    #[default]
     TopLeft,
     TopCenter,
     TopRight,
@@ -90,34 +75,21 @@ pub enum AttachmentPoint {
     BottomCenter,
     BottomRight,
 }
-impl Default for AttachmentPoint {
-    fn default() -> Self {
-        Self::TopLeft
-    }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum TextLineSpacingStyle {
+    #[default]
     AtLeast,
     Exact,
 }
-impl Default for TextLineSpacingStyle {
-    fn default() -> Self {
-        Self::AtLeast
-    }
-}
 
-#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
 pub enum MTextDirection {
+    #[default]
     LeftToRight,
     TopToBottom,
     ByStyle,
 }
-impl Default for MTextDirection {
-    fn default() -> Self {
-        Self::LeftToRight
-    }
-}
 
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub enum MTextBackground {
@@ -125,7 +97,7 @@ pub enum MTextBackground {
     ColorNumber(i16),
 }
 
-#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct TextMirrorFlags {
     pub x: bool,
     pub y: bool,
@@ -181,5 +153,233 @@ pub enum PolylineSmoothType {
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct VertexFlags {
-    // not implemented
+    pub is_extra_vertex_by_curve_fitting: bool,
+    pub has_curve_fit_tangent: bool,
+    pub is_spline_fit_vertex: bool,
+    pub is_spline_frame_control_point: bool,
+    pub is_polyline_3d_vertex: bool,
+    pub is_polygon_mesh_vertex: bool,
+    pub is_polyface_mesh_vertex: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SplineFlags {
+    pub is_closed: bool,
+    pub is_periodic: bool,
+    pub is_rational: bool,
+    pub is_planar: bool,
+    pub is_linear: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ImageDisplayFlags {
+    pub show_image: bool,
+    pub show_image_when_not_aligned_with_screen: bool,
+    pub use_clipping_boundary: bool,
+    pub transparency_is_on: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ImageClipBoundaryType {
+    #[default]
+    Rectangular,
+    Polygonal,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ImageResolutionUnits {
+    #[default]
+    NoUnits,
+    Centimeters,
+    Inch,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MLineJustification {
+    Top,
+    #[default]
+    Zero,
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MLineFlags {
+    pub has_vertex: bool,
+    pub is_closed: bool,
+    pub suppress_start_caps: bool,
+    pub suppress_end_caps: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ZeroSuppression {
+    pub zero_feet: bool,
+    pub zero_inches: bool,
+    pub leading_zeros: bool,
+    pub trailing_zeros: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LinearUnitFormat {
+    Scientific,
+    #[default]
+    Decimal,
+    Engineering,
+    Architectural,
+    Fractional,
+    WindowsDesktop,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum AngularUnitFormat {
+    #[default]
+    DecimalDegrees,
+    DegreesMinutesSeconds,
+    Gradians,
+    Radians,
+    SurveyorsUnits,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FractionFormat {
+    #[default]
+    HorizontalStacking,
+    DiagonalStacking,
+    NotStacked,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DimensionTextMovement {
+    #[default]
+    MoveDimensionLineWithText,
+    AddLeader,
+    MoveTextFreely,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DimensionTextJustification {
+    #[default]
+    Center,
+    NextToExtensionLine1,
+    NextToExtensionLine2,
+    AboveExtensionLine1,
+    AboveExtensionLine2,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ToleranceAlignment {
+    Bottom,
+    #[default]
+    Middle,
+    Top,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ArrowTextFit {
+    TextAndArrowsOutside,
+    ArrowsFirst,
+    TextFirst,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum OrthographicType {
+    #[default]
+    NotOrthographic,
+    Top,
+    Bottom,
+    Front,
+    Back,
+    Left,
+    Right,
+}
+
+/// Drawing units ($INSUNITS, BLOCK_RECORD 70).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Units {
+    #[default]
+    Unitless,
+    Inches,
+    Feet,
+    Miles,
+    Millimeters,
+    Centimeters,
+    Meters,
+    Kilometers,
+    Microinches,
+    Mils,
+    Yards,
+    Angstroms,
+    Nanometers,
+    Microns,
+    Decimeters,
+    Decameters,
+    Hectometers,
+    Gigameters,
+    AstronomicalUnits,
+    LightYears,
+    Parsecs,
+    UsSurveyFeet,
+    UsSurveyInches,
+    UsSurveyYards,
+    UsSurveyMiles,
+}
+impl Units {
+    /// Length of one unit in meters, `None` if unitless.
+    pub fn in_meters(&self) -> Option<f64> {
+        use Units::*;
+        Some(match self {
+            Unitless => return None,
+            Inches => 0.0254,
+            Feet => 0.3048,
+            Miles => 1609.344,
+            Millimeters => 1e-3,
+            Centimeters => 1e-2,
+            Meters => 1.0,
+            Kilometers => 1e3,
+            Microinches => 0.0254e-6,
+            Mils => 0.0254e-3,
+            Yards => 0.9144,
+            Angstroms => 1e-10,
+            Nanometers => 1e-9,
+            Microns => 1e-6,
+            Decimeters => 1e-1,
+            Decameters => 1e1,
+            Hectometers => 1e2,
+            Gigameters => 1e9,
+            AstronomicalUnits => 149_597_870_700.0,
+            LightYears => 9_460_730_472_580_800.0,
+            Parsecs => 30_856_775_814_913_673.0,
+            UsSurveyFeet => 1200.0 / 3937.0,
+            UsSurveyInches => 100.0 / 3937.0,
+            UsSurveyYards => 3600.0 / 3937.0,
+            UsSurveyMiles => 6_336_000.0 / 3937.0,
+        })
+    }
+}
+
+/// Island detection style of a hatch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum HatchStyle {
+    #[default]
+    Normal, // odd parity: islands are skipped, islands within islands are hatched
+    Outer,  // only the outermost area is hatched
+    Ignore, // islands are ignored
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum HatchPatternType {
+    UserDefined,
+    #[default]
+    Predefined,
+    Custom,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HatchBoundaryPathFlags {
+    pub is_external: bool,
+    pub is_polyline: bool,
+    pub is_derived: bool,
+    pub is_textbox: bool,
+    pub is_outermost: bool,
 }
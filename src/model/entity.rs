@@ -34,7 +34,22 @@ pub enum Entity {
     Line(Line),
     Circle(Circle),
     Arc(Arc),
+    Ellipse(Ellipse),
     LwPolyline(LwPolyline),
+    Polyline(Polyline),
+    Spline(Spline),
+    Ray(Ray),
+    XLine(XLine),
+    Image(Image),
+    Wipeout(Image),
+    Viewport(Box<ViewportEntity>),
+    MLine(MLine),
+    Region(ModelerGeometry),
+    Solid3d(ModelerGeometry),
+    Body(ModelerGeometry),
+    Surface(ModelerGeometry),
+    Mesh(Mesh),
+    Hatch(Box<Hatch>),
     NotSupported(String, Vec<Atom<'static>>),
 }
 
@@ -141,6 +156,7 @@ pub struct Dimension {
     pub rotation_angle: Option<f64>,                   // 50
     pub oblique_angle: Option<f64>,                    // 52
     pub leader_length: Option<f64>,                    // 40
+    pub style_overrides: Vec<Atom<'static>>,           // 1001 ACAD, 1000 DSTYLE (DIMSTYLE codes)
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -174,6 +190,28 @@ pub struct Arc {
     pub end_degree: f64,   // 51
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Ellipse {
+    pub center: [f64; 3],                      // 10, 20, 30 (WCS)
+    pub major_axis: [f64; 3],                  // 11, 21, 31 (WCS, relative to the center)
+    pub extrusion_direction: Option<[f64; 3]>, // 210, 220, 230
+    pub axis_ratio: f64,                       // 40 (minor axis / major axis)
+    pub start_parameter: f64,                  // 41 (radian, 0 at the end of the major axis)
+    pub end_parameter: f64,                    // 42 (radian, 2π for a full ellipse)
+}
+impl Default for Ellipse {
+    fn default() -> Self {
+        Self {
+            center: [0.0, 0.0, 0.0],
+            major_axis: [1.0, 0.0, 0.0],
+            extrusion_direction: None,
+            axis_ratio: 1.0,
+            start_parameter: 0.0,
+            end_parameter: 2.0 * std::f64::consts::PI,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct LwPolyline {
     pub vertices: Vec<LwPolylineVertex>,
@@ -193,6 +231,279 @@ pub struct LwPolylineVertex {
     pub bulge: Option<f64>,       // 42
 }
 
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Ray {
+    pub base_point: [f64; 3],     // 10, 20, 30
+    pub unit_direction: [f64; 3], // 11, 21, 31
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct XLine {
+    pub base_point: [f64; 3],     // 10, 20, 30
+    pub unit_direction: [f64; 3], // 11, 21, 31
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Image {
+    pub class_version: i32,                        // 90
+    pub insertion_point: [f64; 3],                 // 10, 20, 30
+    pub u_vector: [f64; 3],                        // 11, 21, 31 (width of a single pixel)
+    pub v_vector: [f64; 3],                        // 12, 22, 32 (height of a single pixel)
+    pub image_size: [f64; 2],                      // 13, 23 (in pixels)
+    pub image_def_handle: u32,                     // 340 (IMAGEDEF object, always 0 for WIPEOUT)
+    pub display_flags: ImageDisplayFlags,          // 70
+    pub is_clipping_on: bool,                      // 280
+    pub brightness: i16,                           // 281 (0-100, default = 50)
+    pub contrast: i16,                             // 282 (0-100, default = 50)
+    pub fade: i16,                                 // 283 (0-100, default = 0)
+    pub image_def_reactor_handle: Option<u32>,     // 360
+    pub clip_boundary_type: ImageClipBoundaryType, // 71
+    pub clip_boundary_vertices: Vec<[f64; 2]>,     // 91, 14, 24 (in pixel coordinates)
+    pub is_inside_clipping: bool,                  // 290 (clip mode)
+}
+impl Default for Image {
+    fn default() -> Self {
+        Self {
+            class_version: 0,
+            insertion_point: [0.0, 0.0, 0.0],
+            u_vector: [1.0, 0.0, 0.0],
+            v_vector: [0.0, 1.0, 0.0],
+            image_size: [0.0, 0.0],
+            image_def_handle: 0,
+            display_flags: ImageDisplayFlags::default(),
+            is_clipping_on: false,
+            brightness: 50,
+            contrast: 50,
+            fade: 0,
+            image_def_reactor_handle: None,
+            clip_boundary_type: ImageClipBoundaryType::default(),
+            clip_boundary_vertices: Vec::new(),
+            is_inside_clipping: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ViewportEntity {
+    pub center: [f64; 3],                  // 10, 20, 30 (in paper space)
+    pub width: f64,                        // 40 (in paper space units)
+    pub height: f64,                       // 41 (in paper space units)
+    pub status: i16, // 68 (0 = off, -1 = on but offscreen, positive = stacking order)
+    pub id: i16,     // 69 (1 means the paper space viewport itself)
+    pub view_center: [f64; 2], // 12, 22 (DCS)
+    pub snap_base_point: [f64; 2], // 13, 23
+    pub snap_spacing: [f64; 2], // 14, 24
+    pub grid_spacing: [f64; 2], // 15, 25
+    pub view_direction: [f64; 3], // 16, 26, 36 (WCS)
+    pub view_target: [f64; 3], // 17, 27, 37 (WCS)
+    pub lens_length: f64, // 42
+    pub front_clip_z: f64, // 43
+    pub back_clip_z: f64, // 44
+    pub view_height: f64, // 45 (in model space units)
+    pub snap_angle: f64, // 50
+    pub twist_degree: f64, // 51
+    pub circle_zoom_percent: i16, // 72
+    pub frozen_layer_handles: Vec<u32>, // 331
+    pub status_flags: i32, // 90
+    pub clip_boundary_handle: Option<u32>, // 340
+    pub plot_style_sheet: String, // 1
+}
+impl Default for ViewportEntity {
+    fn default() -> Self {
+        Self {
+            center: [0.0, 0.0, 0.0],
+            width: 0.0,
+            height: 0.0,
+            status: 0,
+            id: 0,
+            view_center: [0.0, 0.0],
+            snap_base_point: [0.0, 0.0],
+            snap_spacing: [0.0, 0.0],
+            grid_spacing: [0.0, 0.0],
+            view_direction: [0.0, 0.0, 1.0],
+            view_target: [0.0, 0.0, 0.0],
+            lens_length: 50.0,
+            front_clip_z: 0.0,
+            back_clip_z: 0.0,
+            view_height: 0.0,
+            snap_angle: 0.0,
+            twist_degree: 0.0,
+            circle_zoom_percent: 100,
+            frozen_layer_handles: Vec::new(),
+            status_flags: 0,
+            clip_boundary_handle: None,
+            plot_style_sheet: String::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MLine {
+    pub style_name: String,                    // 2
+    pub style_handle: u32,                     // 340 (MLINESTYLE object)
+    pub scale_factor: f64,                     // 40
+    pub justification: MLineJustification,     // 70
+    pub flags: MLineFlags,                     // 71
+    pub start_point: [f64; 3],                 // 10, 20, 30 (WCS)
+    pub extrusion_direction: Option<[f64; 3]>, // 210, 220, 230
+    pub vertices: Vec<MLineVertex>,            // 72 (number of vertices), 73 (number of elements)
+}
+impl Default for MLine {
+    fn default() -> Self {
+        Self {
+            style_name: String::default(),
+            style_handle: 0,
+            scale_factor: 1.0,
+            justification: MLineJustification::default(),
+            flags: MLineFlags::default(),
+            start_point: [0.0, 0.0, 0.0],
+            extrusion_direction: None,
+            vertices: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MLineVertex {
+    pub coord: [f64; 3],                    // 11, 21, 31 (WCS)
+    pub direction: [f64; 3], // 12, 22, 32 (direction of the segment starting at this vertex)
+    pub miter_direction: [f64; 3], // 13, 23, 33
+    pub elements: Vec<MLineSegmentElement>, // one for each element of the style
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MLineSegmentElement {
+    pub parameters: Vec<f64>, // 74, 41 (the first one is the offset along the miter)
+    pub area_fill_parameters: Vec<f64>, // 75, 42
+}
+
+/// ACIS data of REGION, 3DSOLID, BODY and SURFACE entities.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelerGeometry {
+    pub version: i16,       // 70 (modeler format version)
+    pub sat: String,        // 1, 3 (decoded SAT text, lines separated by '\n')
+    pub is_encrypted: bool, // whether the SAT text was stored encrypted
+    pub sab: Vec<u8>,       // 310 (or ACDSDATA section in R2013 and later)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Mesh {
+    pub version: i16,            // 71
+    pub blend_crease: bool,      // 72
+    pub subdivision_level: i32,  // 91
+    pub vertices: Vec<[f64; 3]>, // 92, 10, 20, 30
+    pub faces: Vec<Vec<usize>>, // 93, 90 (vertex count followed by the vertex indices for each face)
+    pub edges: Vec<[usize; 2]>, // 94, 90
+    pub creases: Vec<f64>,      // 95, 140 (one for each edge)
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Hatch {
+    pub elevation_point: [f64; 3], // 10, 20, 30 (OCS, only Z is meaningful)
+    pub extrusion_direction: Option<[f64; 3]>, // 210, 220, 230
+    pub pattern_name: String,      // 2
+    pub is_solid_fill: bool,       // 70
+    pub is_associative: bool,      // 71
+    pub boundary_paths: Vec<HatchBoundaryPath>, // 91
+    pub style: HatchStyle,         // 75
+    pub pattern_type: HatchPatternType, // 76
+    pub pattern_angle_degree: f64, // 52
+    pub pattern_scale: f64,        // 41 (or spacing of user defined patterns)
+    pub is_pattern_double: bool,   // 77
+    pub pattern_lines: Vec<HatchPatternLine>, // 78 (already rotated and scaled)
+    pub pixel_size: Option<f64>,   // 47
+    pub seed_points: Vec<[f64; 2]>, // 98, 10, 20 (OCS)
+}
+impl Default for Hatch {
+    fn default() -> Self {
+        Self {
+            elevation_point: [0.0, 0.0, 0.0],
+            extrusion_direction: None,
+            pattern_name: String::default(),
+            is_solid_fill: false,
+            is_associative: false,
+            boundary_paths: Vec::new(),
+            style: HatchStyle::default(),
+            pattern_type: HatchPatternType::default(),
+            pattern_angle_degree: 0.0,
+            pattern_scale: 1.0,
+            is_pattern_double: false,
+            pattern_lines: Vec::new(),
+            pixel_size: None,
+            seed_points: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HatchBoundaryPath {
+    pub flags: HatchBoundaryPathFlags, // 92
+    pub boundary: HatchBoundary,
+    pub source_handles: Vec<u32>, // 97, 330 (boundary objects of an associative hatch)
+}
+
+/// Geometry of a hatch boundary path in OCS.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum HatchBoundary {
+    Polyline(LwPolyline), // 72, 73, 93, 10, 20, 42 (only the vertices and `is_closed` are used)
+    Edges(Vec<HatchEdge>), // 93, 72
+}
+impl Default for HatchBoundary {
+    fn default() -> Self {
+        Self::Edges(Vec::new())
+    }
+}
+
+/// An edge of a hatch boundary path in OCS.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum HatchEdge {
+    // 72 = 1
+    Line {
+        start: [f64; 2], // 10, 20
+        end: [f64; 2],   // 11, 21
+    },
+    // 72 = 2 (clockwise arcs store the angles measured clockwise)
+    CircularArc {
+        center: [f64; 2],          // 10, 20
+        radius: f64,               // 40
+        start_degree: f64,         // 50
+        end_degree: f64,           // 51
+        is_counterclockwise: bool, // 73
+    },
+    // 72 = 3 (clockwise arcs store the angles measured clockwise)
+    EllipticArc {
+        center: [f64; 2],          // 10, 20
+        major_axis: [f64; 2],      // 11, 21 (relative to the center)
+        axis_ratio: f64,           // 40
+        start_degree: f64,         // 50 (parameter in degrees)
+        end_degree: f64,           // 51
+        is_counterclockwise: bool, // 73
+    },
+    // 72 = 4
+    Spline(HatchSplineEdge),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HatchSplineEdge {
+    pub degree: usize,                   // 94
+    pub is_rational: bool,               // 73
+    pub is_periodic: bool,               // 74
+    pub knots: Vec<f64>,                 // 95, 40
+    pub control_points: Vec<[f64; 2]>,   // 96, 10, 20
+    pub weights: Vec<f64>,               // 42 (if rational)
+    pub fit_points: Vec<[f64; 2]>,       // 97, 11, 21
+    pub start_tangent: Option<[f64; 2]>, // 12, 22
+    pub end_tangent: Option<[f64; 2]>,   // 13, 23
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HatchPatternLine {
+    pub angle_degree: f64,      // 53
+    pub base_point: [f64; 2],   // 43, 44
+    pub offset: [f64; 2],       // 45, 46 (between successive lines)
+    pub dash_lengths: Vec<f64>, // 79, 49 (positive for dashes, negative for spaces and 0 for dots)
+}
+
 #[allow(non_snake_case)]
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Polyline {
@@ -207,6 +518,7 @@ pub struct Polyline {
     pub smooth_surface_N_density: f64,           // 74
     pub smooth_type: Option<PolylineSmoothType>, // 75
     pub extrusion_direction: Option<[f64; 3]>,   // 210, 220, 230
+    pub vertices: Vec<Vertex>,                   // VERTEX entities up to SEQEND
 }
 
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -220,3 +532,19 @@ pub struct Vertex {
     pub polyface_mesh_vertex_index: Option<[usize; 4]>,
     pub id: i32,
 }
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Spline {
+    pub normal: Option<[f64; 3]>,             // 210, 220, 230 (if planar)
+    pub flags: SplineFlags,                   // 70
+    pub degree: usize,                        // 71
+    pub knots: Vec<f64>,                      // 72, 40
+    pub weights: Vec<f64>,                    // 41 (if rational)
+    pub control_points: Vec<[f64; 3]>,        // 73, 10, 20, 30
+    pub fit_points: Vec<[f64; 3]>,            // 74, 11, 21, 31
+    pub knot_tolerance: Option<f64>,          // 42
+    pub control_point_tolerance: Option<f64>, // 43
+    pub fit_tolerance: Option<f64>,           // 44
+    pub start_tangent: Option<[f64; 3]>,      // 12, 22, 32
+    pub end_tangent: Option<[f64; 3]>,        // 13, 23, 33
+}
@@ -1,10 +1,12 @@
 mod data;
 mod entity;
+mod object;
 mod table;
 mod text_format;
 use crate::{Node, ParseResult};
 pub use data::*;
 pub use entity::*;
+pub use object::*;
 pub use table::*;
 pub use text_format::*;
 
@@ -14,6 +16,7 @@ pub struct Document {
     pub tables: Vec<TableNode>,
     pub blocks: Vec<BlockNode>,
     pub entities: Vec<EntityNode>,
+    pub objects: Vec<ObjectNode>,
 }
 impl Document {
     pub fn open(path: impl AsRef<std::path::Path>) -> ParseResult<Self> {
@@ -31,17 +34,71 @@ impl Document {
     pub fn parse_atoms(atoms: &[crate::Atom]) -> Self {
         Self::parse_nodes(&crate::Node::parse_atoms(atoms))
     }
+    /// Finds a header variable such as "$LTSCALE".
+    pub fn header(&self, name: &str) -> Option<&Node<'static>> {
+        self.headers.iter().find(|node| node.node_type == name)
+    }
+    pub fn find_table_entry(&self, handle: u32) -> Option<&TableEntry> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find(|entry| entry.handle == handle)
+    }
+    pub fn find_object(&self, handle: u32) -> Option<&ObjectNode> {
+        self.objects
+            .iter()
+            .find(|node| node.header.handle == handle)
+    }
+    pub fn image_def(&self, image: &Image) -> Option<&ImageDef> {
+        match self.find_object(image.image_def_handle) {
+            Some(ObjectNode {
+                object: Object::ImageDef(image_def),
+                ..
+            }) => Some(image_def),
+            _ => None,
+        }
+    }
+    /// The BLOCK_RECORD table entry of a block definition.
+    pub fn block_record(&self, block: &BlockNode) -> Option<&Block> {
+        match self.find_table_entry(block.block_record_handle) {
+            Some(TableEntry {
+                record: TableRecord::Block(record),
+                ..
+            }) => Some(record),
+            _ => None,
+        }
+    }
+    /// File paths of the IMAGEDEF objects referenced by IMAGE entities
+    /// in the entities section and in block definitions, without duplicates.
+    pub fn referenced_image_files(&self) -> Vec<&str> {
+        let mut paths: Vec<&str> = vec![];
+        let entities = self
+            .entities
+            .iter()
+            .chain(self.blocks.iter().flat_map(|block| block.entities.iter()));
+        for entity in entities {
+            if let Entity::Image(image) = &entity.entity {
+                if let Some(image_def) = self.image_def(image) {
+                    if !paths.contains(&image_def.file_name.as_str()) {
+                        paths.push(&image_def.file_name);
+                    }
+                }
+            }
+        }
+        paths
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BlockNode {
-    pub handle: u32,             // 5
-    pub layer: String,           // 8
-    pub block_name: String,      // 2, 3
-    pub block_flags: BlockFlags, // 70
-    pub base_point: [f64; 3],    // 10, 20, 30
-    pub xref_path_name: String,  // 1
-    pub description: String,     // 4
+    pub handle: u32,              // 5
+    pub block_record_handle: u32, // 330 (BLOCK_RECORD table entry)
+    pub layer: String,            // 8
+    pub block_name: String,       // 2, 3
+    pub block_flags: BlockFlags,  // 70
+    pub base_point: [f64; 3],     // 10, 20, 30
+    pub xref_path_name: String,   // 1
+    pub description: String,      // 4
     pub entities: Vec<EntityNode>,
 }
 
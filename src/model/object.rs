@@ -0,0 +1,92 @@
+use super::data::*;
+use crate::Atom;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ObjectNode {
+    pub header: ObjectHeader,
+    pub object: Object,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ObjectHeader {
+    pub handle: u32,       // 5    String
+    pub owner_handle: u32, // 330  String
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum Object {
+    Dictionary(Dictionary),
+    XRecord(XRecord),
+    ImageDef(ImageDef),
+    Layout(Box<Layout>),
+    MLineStyle(MLineStyle),
+    NotSupported(String, Vec<Atom<'static>>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Dictionary {
+    pub is_hard_owner: bool,         // 280
+    pub cloning_flag: i16,           // 281
+    pub entries: Vec<(String, u32)>, // 3, 350 or 360
+}
+impl Dictionary {
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|&(_, handle)| handle)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct XRecord {
+    pub cloning_flag: Option<i16>, // 280
+    pub data: Vec<Atom<'static>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ImageDef {
+    pub class_version: i32,                     // 90
+    pub file_name: String,                      // 1
+    pub image_size: [f64; 2],                   // 10, 20 (in pixels)
+    pub pixel_size: [f64; 2], // 11, 21 (default size of one pixel in AutoCAD units)
+    pub is_loaded: bool,      // 280
+    pub resolution_units: ImageResolutionUnits, // 281
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Layout {
+    pub page_setup_name: String,                  // 1 (AcDbPlotSettings)
+    pub name: String,                             // 1 (AcDbLayout)
+    pub flags: i16,                               // 70 (AcDbLayout)
+    pub tab_order: i16,                           // 71
+    pub limits_min: [f64; 2],                     // 10, 20
+    pub limits_max: [f64; 2],                     // 11, 21
+    pub insertion_base: [f64; 3],                 // 12, 22, 32
+    pub extents_min: [f64; 3],                    // 14, 24, 34
+    pub extents_max: [f64; 3],                    // 15, 25, 35
+    pub elevation: f64,                           // 146
+    pub ucs_origin: [f64; 3],                     // 13, 23, 33
+    pub ucs_x_axis: [f64; 3],                     // 16, 26, 36
+    pub ucs_y_axis: [f64; 3],                     // 17, 27, 37
+    pub block_record_handle: u32,                 // 330 (AcDbLayout)
+    pub last_active_viewport_handle: Option<u32>, // 331
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MLineStyle {
+    pub name: String,                     // 2
+    pub flags: i16,                       // 70
+    pub description: String,              // 3
+    pub fill_color: ColorNumber,          // 62
+    pub start_angle_degree: f64,          // 51
+    pub end_angle_degree: f64,            // 52
+    pub elements: Vec<MLineStyleElement>, // 71 (number of elements)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MLineStyleElement {
+    pub offset: f64,            // 49
+    pub color: ColorNumber,     // 62
+    pub line_type: LineTypeRef, // 6
+}
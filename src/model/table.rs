@@ -1,3 +1,5 @@
+use super::data::*;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TableNode {
     pub handle: u32,
@@ -25,94 +27,215 @@ pub enum TableRecord {
     NotSupported(crate::Node<'static>),
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct RegApp {}
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RegApp {
+    pub flags: u16, // 70
+}
+impl RegApp {
+    pub fn is_xref_dependent(&self) -> bool {
+        self.flags & 16 != 0
+    }
+}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct Block {}
+pub struct Block {
+    pub layout_handle: Option<u32>, // 340 (LAYOUT object)
+    pub insert_units: Units,        // 70
+    pub is_explodable: bool,        // 280
+    pub is_scalable: bool,          // 281
+    pub preview: Vec<u8>,           // 310 (BMP, without the file header)
+}
+impl Default for Block {
+    fn default() -> Self {
+        Self {
+            layout_handle: None,
+            insert_units: Units::Unitless,
+            is_explodable: true,
+            is_scalable: true,
+            preview: Vec::new(),
+        }
+    }
+}
 
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DimStyle {
-    pub general_dimensioning_suffix: String,   // 3 DIMPOST
-    pub alternate_dimensioning_suffix: String, // 4 DIMAPOST
-    pub arrow_block_name: String,              // 5 DIMBLK (obsolete, now object ID)
-    pub arrow1_block_name: String,             // 6 DIMBLK1 (obsolete, now object ID)
-    pub arrow2_block_name: String,             // 7 DIMBLK2 (obsolete, now object ID)
-    pub scale_factor: f64,                     // 40 DIMSCALE
-    pub arrow_size: f64,                       // 41 DIMASZ
-    pub extension_line_offset: f64,            // 42 DIMEXO
-    pub dimension_line_increment: f64,         // 43 DIMDLI
-    pub extension_line_extension: f64,         // 44 DIMEXE
-    pub rounding_value: f64,                   // 45 DIMRND
-    pub dimension_line_extension: f64,         // 46 DIMDLE
-    pub plus_tolerance: f64,                   // 47 DIMTP
-    pub minus_tolerance: f64,                  // 48 DIMTM
-    pub text_height: f64,                      // 140 DIMTXT
-    pub center_mark_size: f64,                 // 141 DIMCEN
-    pub tick_size: f64,                        // 142 DIMTSZ
-    pub alternate_unit_scale_factor: f64,      // 143 DIMALTF
-    pub linear_measurement_scale_factor: f64,  // 144 DIMLFAC
-    pub text_vertical_position: f64,           // 145 DIMTVP
-    pub tolerance_display_scale_factor: f64,   // 146 DIMTFAC
-    pub dimension_line_gap: f64,               // 147 DIMGAP
-    pub alternate_unit_rounding: f64,          // 148 DIMALTRND
-    pub tolerance: i16,                        // 71 DIMTOL
-    pub dimension_limits: i16,                 // 72 DIMLIM
-    pub text_inside_horizontal: i16,           // 73 DIMTIH
-    pub text_outside_horizontal: i16,          // 74 DIMTOH
-    pub extension_line1_suppressed: bool,      // 75 DIMSE1
-    pub extension_line2_suppressed: bool,      // 76 DIMSE2
-    pub text_above_dimension_line: bool,       // 77 DIMTAD
-                                               // pub _: i16,    // 78 DIMZIN
-                                               // pub _: i16,    // 79 DIMAZIN
-                                               // pub _: i16,    // 170 DIMALT
-                                               // pub _: i16,    // 171 DIMALTD
-                                               // pub _: i16,    // 172 DIMTOFL
-                                               // pub _: i16,    // 173 DIMSAH
-                                               // pub _: i16,    // 174 DIMTIX
-                                               // pub _: i16,    // 175 DIMSOXD
-                                               // pub _: i16,    // 176 DIMCLRD
-                                               // pub _: i16,    // 177 DIMCLRE
-                                               // pub _: i16,    // 178 DIMCLRT
-                                               // pub _: i16,    // 179 DIMADEC
-                                               // pub _: i16,       // 270 DIMUNIT (obsolete, now use DIMLUNIT AND DIMFRAC)
-                                               // pub _: i16,       // 271 DIMDEC
-                                               // pub _: i16,       // 272 DIMTDEC
-                                               // pub _: i16,       // 273 DIMALTU
-                                               // pub _: i16,       // 274 DIMALTTD
-                                               // pub _: i16,       // 275 DIMAUNIT
-                                               // pub _: i16,       // 276 DIMFRAC
-                                               // pub _: i16,       // 277 DIMLUNIT
-                                               // pub _: i16,       // 278 DIMDSEP
-                                               // pub _: i16,       // 279 DIMTMOVE
-                                               // pub _: i16,       // 280 DIMJUST
-                                               // pub _: i16,       // 281 DIMSD1
-                                               // pub _: i16,       // 282 DIMSD2
-                                               // pub _: i16,       // 283 DIMTOLJ
-                                               // pub _: i16,       // 284 DIMTZIN
-                                               // pub _: i16,       // 285 DIMALTZ
-                                               // pub _: i16,       // 286 DIMALTTZ
-                                               // pub _: i16,       // 287 DIMFIT (obsolete, now use DIMATFIT and DIMTMOVE)
-                                               // pub _: i16,       // 288 DIMUPT
-                                               // pub _: i16,       // 289 DIMATFIT
-                                               // pub _handle: u32, // 340 DIMTXSTY (handle of referenced STYLE)
-                                               // pub _handle: u32, // 341 DIMLDRBLK (handle of referenced BLOCK)
-                                               // pub _handle: u32, // 342 DIMBLK (handle of referenced BLOCK)
-                                               // pub _handle: u32, // 343 DIMBLK1 (handle of referenced BLOCK)
-                                               // pub _handle: u32, // 344 DIMBLK2 (handle of referenced BLOCK)
-                                               // pub _: i16,       // 371 DIMLWD (lineweight enum value)
-                                               // pub _: i16,       // 372 DIMLWE (lineweight enum value)
+    pub general_dimensioning_suffix: String,       // 3 DIMPOST
+    pub alternate_dimensioning_suffix: String,     // 4 DIMAPOST
+    pub arrow_block_name: String,                  // 5 DIMBLK (obsolete, now object ID)
+    pub arrow1_block_name: String,                 // 6 DIMBLK1 (obsolete, now object ID)
+    pub arrow2_block_name: String,                 // 7 DIMBLK2 (obsolete, now object ID)
+    pub scale_factor: f64,                         // 40 DIMSCALE
+    pub arrow_size: f64,                           // 41 DIMASZ
+    pub extension_line_offset: f64,                // 42 DIMEXO
+    pub dimension_line_increment: f64,             // 43 DIMDLI
+    pub extension_line_extension: f64,             // 44 DIMEXE
+    pub rounding_value: f64,                       // 45 DIMRND
+    pub dimension_line_extension: f64,             // 46 DIMDLE
+    pub plus_tolerance: f64,                       // 47 DIMTP
+    pub minus_tolerance: f64,                      // 48 DIMTM
+    pub text_height: f64,                          // 140 DIMTXT
+    pub center_mark_size: f64,                     // 141 DIMCEN
+    pub tick_size: f64,                            // 142 DIMTSZ
+    pub alternate_unit_scale_factor: f64,          // 143 DIMALTF
+    pub linear_measurement_scale_factor: f64,      // 144 DIMLFAC
+    pub text_vertical_position: f64,               // 145 DIMTVP
+    pub tolerance_display_scale_factor: f64,       // 146 DIMTFAC
+    pub dimension_line_gap: f64,                   // 147 DIMGAP
+    pub alternate_unit_rounding: f64,              // 148 DIMALTRND
+    pub tolerance: i16,                            // 71 DIMTOL
+    pub dimension_limits: i16,                     // 72 DIMLIM
+    pub text_inside_horizontal: i16,               // 73 DIMTIH
+    pub text_outside_horizontal: i16,              // 74 DIMTOH
+    pub extension_line1_suppressed: bool,          // 75 DIMSE1
+    pub extension_line2_suppressed: bool,          // 76 DIMSE2
+    pub text_above_dimension_line: bool,           // 77 DIMTAD
+    pub zero_suppression: ZeroSuppression,         // 78 DIMZIN
+    pub angular_zero_suppression: ZeroSuppression, // 79 DIMAZIN
+    pub alternate_units_enabled: bool,             // 170 DIMALT
+    pub alternate_unit_decimal_places: i16,        // 171 DIMALTD
+    pub force_dimension_line_inside: bool,         // 172 DIMTOFL
+    pub use_separate_arrow_blocks: bool,           // 173 DIMSAH
+    pub force_text_inside: bool,                   // 174 DIMTIX
+    pub suppress_outside_dimension_lines: bool,    // 175 DIMSOXD
+    pub dimension_line_color: ColorNumber,         // 176 DIMCLRD
+    pub extension_line_color: ColorNumber,         // 177 DIMCLRE
+    pub text_color: ColorNumber,                   // 178 DIMCLRT
+    pub angular_decimal_places: i16,               // 179 DIMADEC (-1 means DIMDEC)
+    pub unit: i16,           // 270 DIMUNIT (obsolete, now use DIMLUNIT AND DIMFRAC)
+    pub decimal_places: i16, // 271 DIMDEC
+    pub tolerance_decimal_places: i16, // 272 DIMTDEC
+    pub alternate_unit_format: LinearUnitFormat, // 273 DIMALTU
+    pub alternate_tolerance_decimal_places: i16, // 274 DIMALTTD
+    pub angular_unit_format: AngularUnitFormat, // 275 DIMAUNIT
+    pub fraction_format: FractionFormat, // 276 DIMFRAC
+    pub linear_unit_format: LinearUnitFormat, // 277 DIMLUNIT
+    pub decimal_separator: Option<char>, // 278 DIMDSEP (default = '.')
+    pub text_movement: DimensionTextMovement, // 279 DIMTMOVE
+    pub text_justification: DimensionTextJustification, // 280 DIMJUST
+    pub dimension_line1_suppressed: bool, // 281 DIMSD1
+    pub dimension_line2_suppressed: bool, // 282 DIMSD2
+    pub tolerance_alignment: ToleranceAlignment, // 283 DIMTOLJ
+    pub tolerance_zero_suppression: ZeroSuppression, // 284 DIMTZIN
+    pub alternate_zero_suppression: ZeroSuppression, // 285 DIMALTZ
+    pub alternate_tolerance_zero_suppression: ZeroSuppression, // 286 DIMALTTZ
+    pub fit: i16,            // 287 DIMFIT (obsolete, now use DIMATFIT and DIMTMOVE)
+    pub cursor_updates_text: bool, // 288 DIMUPT
+    pub arrow_text_fit: ArrowTextFit, // 289 DIMATFIT
+    pub text_style_handle: Option<u32>, // 340 DIMTXSTY (handle of referenced STYLE)
+    pub leader_arrow_block_handle: Option<u32>, // 341 DIMLDRBLK (handle of referenced BLOCK)
+    pub arrow_block_handle: Option<u32>, // 342 DIMBLK (handle of referenced BLOCK)
+    pub arrow1_block_handle: Option<u32>, // 343 DIMBLK1 (handle of referenced BLOCK)
+    pub arrow2_block_handle: Option<u32>, // 344 DIMBLK2 (handle of referenced BLOCK)
+    pub dimension_line_weight: i16, // 371 DIMLWD (lineweight enum value)
+    pub extension_line_weight: i16, // 372 DIMLWE (lineweight enum value)
+}
+impl Default for DimStyle {
+    // the DXF defaults of the unit and format variables; sizes and distances are 0
+    fn default() -> Self {
+        Self {
+            general_dimensioning_suffix: String::default(),
+            alternate_dimensioning_suffix: String::default(),
+            arrow_block_name: String::default(),
+            arrow1_block_name: String::default(),
+            arrow2_block_name: String::default(),
+            scale_factor: 1.0,
+            arrow_size: 0.0,
+            extension_line_offset: 0.0,
+            dimension_line_increment: 0.0,
+            extension_line_extension: 0.0,
+            rounding_value: 0.0,
+            dimension_line_extension: 0.0,
+            plus_tolerance: 0.0,
+            minus_tolerance: 0.0,
+            text_height: 0.0,
+            center_mark_size: 0.0,
+            tick_size: 0.0,
+            alternate_unit_scale_factor: 25.4,
+            linear_measurement_scale_factor: 1.0,
+            text_vertical_position: 0.0,
+            tolerance_display_scale_factor: 1.0,
+            dimension_line_gap: 0.0,
+            alternate_unit_rounding: 0.0,
+            tolerance: 0,
+            dimension_limits: 0,
+            text_inside_horizontal: 0,
+            text_outside_horizontal: 0,
+            extension_line1_suppressed: false,
+            extension_line2_suppressed: false,
+            text_above_dimension_line: false,
+            zero_suppression: ZeroSuppression::default(),
+            angular_zero_suppression: ZeroSuppression::default(),
+            alternate_units_enabled: false,
+            alternate_unit_decimal_places: 2,
+            force_dimension_line_inside: false,
+            use_separate_arrow_blocks: false,
+            force_text_inside: false,
+            suppress_outside_dimension_lines: false,
+            dimension_line_color: ColorNumber::default(),
+            extension_line_color: ColorNumber::default(),
+            text_color: ColorNumber::default(),
+            angular_decimal_places: -1,
+            unit: 0,
+            decimal_places: 4,
+            tolerance_decimal_places: 4,
+            alternate_unit_format: LinearUnitFormat::default(),
+            alternate_tolerance_decimal_places: 2,
+            angular_unit_format: AngularUnitFormat::default(),
+            fraction_format: FractionFormat::default(),
+            linear_unit_format: LinearUnitFormat::default(),
+            decimal_separator: None,
+            text_movement: DimensionTextMovement::default(),
+            text_justification: DimensionTextJustification::default(),
+            dimension_line1_suppressed: false,
+            dimension_line2_suppressed: false,
+            tolerance_alignment: ToleranceAlignment::default(),
+            tolerance_zero_suppression: ZeroSuppression::default(),
+            alternate_zero_suppression: ZeroSuppression::default(),
+            alternate_tolerance_zero_suppression: ZeroSuppression::default(),
+            fit: 0,
+            cursor_updates_text: false,
+            arrow_text_fit: ArrowTextFit::default(),
+            text_style_handle: None,
+            leader_arrow_block_handle: None,
+            arrow_block_handle: None,
+            arrow1_block_handle: None,
+            arrow2_block_handle: None,
+            dimension_line_weight: 0,
+            extension_line_weight: 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct Layer {
-    pub is_plotted: bool,
-    pub flags: u16,
-    pub color_number: Option<u8>, // None means turned-off
-    pub line_type: Option<String>,
-    pub line_weight: Option<i16>,
-    pub plot_style_handle: Option<u32>,
-    pub material_handle: Option<u32>,
+    pub is_plotted: bool,                         // 290
+    pub flags: LayerFlags,                        // 70
+    pub color_number: Option<u8>,                 // 62 (None means turned-off)
+    pub is_off: bool,                             // 62 (negative color number)
+    pub color_index: u8,                          // 62 (absolute value, also if turned off)
+    pub color_rgb: Option<Rgb>,                   // 420
+    pub line_type: Option<String>,                // 6
+    pub line_weight: Option<i16>,                 // 370
+    pub plot_style_handle: Option<u32>,           // 390
+    pub material_handle: Option<u32>,             // 347
+    pub transparency: Option<i32>,                // 1001 AcCmTransparency, 1071
+    pub extension_dictionary_handle: Option<u32>, // 102 {ACAD_XDICTIONARY, 360
+}
+impl Layer {
+    /// Entities on the layer are displayed (the layer is neither off nor frozen).
+    pub fn is_visible(&self) -> bool {
+        !self.is_off && !self.flags.is_frozen
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LayerFlags {
+    pub is_frozen: bool,
+    pub is_frozen_in_new_viewports: bool,
+    pub is_locked: bool,
+    pub is_xref_dependent: bool,
+    pub is_xref_resolved: bool,
+    pub is_referenced: bool,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -120,7 +243,8 @@ pub struct LineType {
     pub flags: u16,
     pub description: String,
     pub total_pattern_length: f64,
-    pub pattern_lengths: Vec<f64>,
+    pub pattern_lengths: Vec<f64>, // positive for dashes, negative for spaces and 0 for dots
+    pub elements: Vec<LineTypeElement>, // the same lengths with their embedded texts and shapes
 }
 impl LineType {
     pub fn is_continuous(&self) -> bool {
@@ -128,14 +252,217 @@ impl LineType {
     }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct TextStyle {}
+/// A dash, dot or space of a linetype pattern, optionally with an embedded text or shape.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LineTypeElement {
+    pub length: f64,                 // 49
+    pub flags: LineTypeElementFlags, // 74
+    pub shape_number: i16,           // 75 (0 for text)
+    pub style_handle: Option<u32>,   // 340 (STYLE table entry)
+    pub scale: f64,                  // 46
+    pub rotation_degree: f64,        // 50
+    pub offset: [f64; 2],            // 44, 45
+    pub text: Option<String>,        // 9
+}
+impl Default for LineTypeElement {
+    fn default() -> Self {
+        Self {
+            length: 0.0,
+            flags: LineTypeElementFlags::default(),
+            shape_number: 0,
+            style_handle: None,
+            scale: 1.0,
+            rotation_degree: 0.0,
+            offset: [0.0, 0.0],
+            text: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LineTypeElementFlags {
+    pub is_rotation_absolute: bool, // otherwise relative to the line direction
+    pub is_text: bool,
+    pub is_shape: bool,
+}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct Ucs {}
+pub struct TextStyle {
+    pub flags: u16,                        // 70
+    pub fixed_height: f64,                 // 40 (0 = not fixed)
+    pub width_factor: f64,                 // 41
+    pub oblique_degree: f64,               // 50
+    pub generation_flags: TextMirrorFlags, // 71 (x = backward, y = upside down)
+    pub last_height: f64,                  // 42
+    pub font_file_name: String,            // 3
+    pub big_font_file_name: String,        // 4
+    pub true_type_family: Option<String>,  // 1001 ACAD, 1000
+    pub true_type_flags: Option<i32>,      // 1001 ACAD, 1071
+}
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            flags: 0,
+            fixed_height: 0.0,
+            width_factor: 1.0,
+            oblique_degree: 0.0,
+            generation_flags: TextMirrorFlags::default(),
+            last_height: 2.5,
+            font_file_name: "txt".to_owned(),
+            big_font_file_name: String::default(),
+            true_type_family: None,
+            true_type_flags: None,
+        }
+    }
+}
+impl TextStyle {
+    /// The record describes a shape file (SHX) rather than a text font.
+    pub fn is_shape_file(&self) -> bool {
+        self.flags & 1 != 0
+    }
+    pub fn is_vertical(&self) -> bool {
+        self.flags & 4 != 0
+    }
+    pub fn is_xref_dependent(&self) -> bool {
+        self.flags & 16 != 0
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct Ucs {
+    pub flags: u16,                                              // 70
+    pub origin: [f64; 3],                                        // 10, 20, 30 (WCS)
+    pub x_axis: [f64; 3],                                        // 11, 21, 31 (WCS)
+    pub y_axis: [f64; 3],                                        // 12, 22, 32 (WCS)
+    pub elevation: f64,                                          // 146
+    pub base_ucs_handle: Option<u32>,                            // 346
+    pub orthographic_origins: Vec<(OrthographicType, [f64; 3])>, // 71, 13, 23, 33
+}
+
+/// UCS associated with a view or a viewport.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ViewUcs {
+    pub origin: [f64; 3],                    // 110, 120, 130
+    pub x_axis: [f64; 3],                    // 111, 121, 131
+    pub y_axis: [f64; 3],                    // 112, 122, 132
+    pub orthographic_type: OrthographicType, // 79
+    pub elevation: f64,                      // 146
+    pub named_ucs_handle: Option<u32>,       // 345
+    pub base_ucs_handle: Option<u32>,        // 346
+}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct View {}
+pub struct View {
+    pub flags: u16,                       // 70
+    pub center: [f64; 2],                 // 10, 20 (DCS)
+    pub height: f64,                      // 40
+    pub width: f64,                       // 41
+    pub view_direction: [f64; 3],         // 11, 21, 31 (WCS, from target)
+    pub target: [f64; 3],                 // 12, 22, 32 (WCS)
+    pub lens_length: f64,                 // 42
+    pub front_clip_z: f64,                // 43
+    pub back_clip_z: f64,                 // 44
+    pub twist_degree: f64,                // 50
+    pub view_mode: i16,                   // 71 (VIEWMODE)
+    pub render_mode: i16,                 // 281
+    pub is_camera_plottable: bool,        // 73
+    pub background_handle: Option<u32>,   // 332
+    pub live_section_handle: Option<u32>, // 334
+    pub visual_style_handle: Option<u32>, // 348
+    pub ucs: Option<ViewUcs>,             // 72 (1 = UCS is associated), 110.., 79, 146, 345, 346
+}
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            flags: 0,
+            center: [0.0, 0.0],
+            height: 1.0,
+            width: 1.0,
+            view_direction: [0.0, 0.0, 1.0],
+            target: [0.0, 0.0, 0.0],
+            lens_length: 50.0,
+            front_clip_z: 0.0,
+            back_clip_z: 0.0,
+            twist_degree: 0.0,
+            view_mode: 0,
+            render_mode: 0,
+            is_camera_plottable: false,
+            background_handle: None,
+            live_section_handle: None,
+            visual_style_handle: None,
+            ucs: None,
+        }
+    }
+}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct Viewport {}
+pub struct Viewport {
+    pub flags: u16,                       // 70
+    pub lower_left: [f64; 2],             // 10, 20 (0..1 of the drawing window)
+    pub upper_right: [f64; 2],            // 11, 21
+    pub view_center: [f64; 2],            // 12, 22 (DCS)
+    pub snap_base_point: [f64; 2],        // 13, 23
+    pub snap_spacing: [f64; 2],           // 14, 24
+    pub grid_spacing: [f64; 2],           // 15, 25
+    pub view_direction: [f64; 3],         // 16, 26, 36 (WCS, from target)
+    pub view_target: [f64; 3],            // 17, 27, 37 (WCS)
+    pub view_height: f64,                 // 40 or 45
+    pub aspect_ratio: f64,                // 41 (width / height)
+    pub lens_length: f64,                 // 42
+    pub front_clip_z: f64,                // 43
+    pub back_clip_z: f64,                 // 44
+    pub snap_rotation_degree: f64,        // 50
+    pub twist_degree: f64,                // 51
+    pub view_mode: i16,                   // 71 (VIEWMODE)
+    pub circle_zoom_percent: i16,         // 72
+    pub fast_zoom: bool,                  // 73
+    pub ucs_icon: i16,                    // 74 (UCSICON)
+    pub snap_on: bool,                    // 75
+    pub grid_on: bool,                    // 76
+    pub snap_style: i16,                  // 77
+    pub snap_isopair: i16,                // 78
+    pub render_mode: i16,                 // 281
+    pub ucs_per_viewport: bool,           // 65 (UCSVP)
+    pub ucs: ViewUcs,                     // 110.., 79, 146, 345, 346
+    pub grid_flags: i16,                  // 60
+    pub grid_major: i16,                  // 61
+    pub background_handle: Option<u32>,   // 332
+    pub visual_style_handle: Option<u32>, // 348
+}
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            flags: 0,
+            lower_left: [0.0, 0.0],
+            upper_right: [1.0, 1.0],
+            view_center: [0.0, 0.0],
+            snap_base_point: [0.0, 0.0],
+            snap_spacing: [10.0, 10.0],
+            grid_spacing: [10.0, 10.0],
+            view_direction: [0.0, 0.0, 1.0],
+            view_target: [0.0, 0.0, 0.0],
+            view_height: 1.0,
+            aspect_ratio: 1.0,
+            lens_length: 50.0,
+            front_clip_z: 0.0,
+            back_clip_z: 0.0,
+            snap_rotation_degree: 0.0,
+            twist_degree: 0.0,
+            view_mode: 0,
+            circle_zoom_percent: 1000,
+            fast_zoom: true,
+            ucs_icon: 3,
+            snap_on: false,
+            grid_on: false,
+            snap_style: 0,
+            snap_isopair: 0,
+            render_mode: 0,
+            ucs_per_viewport: true,
+            ucs: ViewUcs::default(),
+            grid_flags: 0,
+            grid_major: 5,
+            background_handle: None,
+            visual_style_handle: None,
+        }
+    }
+}
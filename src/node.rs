@@ -33,11 +33,14 @@ impl<'a> Node<'a> {
             node_type: Cow::Owned(self.node_type.clone().into_owned()),
             atoms: Cow::Owned(self.atoms.iter().map(|a| a.clone().into_owned()).collect()),
             nodes: self.nodes.iter().map(|n| n.to_owned()).collect(),
-            end: self.end.as_ref().map(|n| Box::new(Self::to_owned(&*n))),
+            end: self.end.as_ref().map(|n| Box::new(Self::to_owned(n))),
         }
     }
     pub fn parse_atoms(atoms: &'a [Atom<'a>]) -> Vec<Self> {
-        NodeParser { atoms }.parse_nodes(0).unwrap_or_default().0
+        NodeParser { atoms }
+            .parse_nodes(0, false)
+            .unwrap_or_default()
+            .0
     }
     pub fn iter_atoms(&self) -> Box<dyn Iterator<Item = Atom<'a>> + '_> {
         Box::new(
@@ -67,9 +70,13 @@ struct NodeParser<'a> {
     atoms: &'a [Atom<'a>],
 }
 impl<'a> NodeParser<'a> {
-    fn parse_nodes(&self, mut start: usize) -> Option<(Vec<Node<'a>>, Node<'a>, usize)> {
+    fn parse_nodes(
+        &self,
+        mut start: usize,
+        in_header: bool,
+    ) -> Option<(Vec<Node<'a>>, Node<'a>, usize)> {
         let mut nodes = vec![];
-        while let Some((node, end)) = self.parse_node(start) {
+        while let Some((node, end)) = self.parse_node(start, in_header) {
             if !node.node_type.starts_with('$') && node.node_type.contains("END") {
                 return Some((nodes, node, end));
             }
@@ -86,7 +93,7 @@ impl<'a> NodeParser<'a> {
             None
         }
     }
-    fn parse_node(&self, start: usize) -> Option<(Node<'a>, usize)> {
+    fn parse_node(&self, start: usize, in_header: bool) -> Option<(Node<'a>, usize)> {
         fn is_container_type(node: &Node) -> bool {
             const CONTAINER_TYPES: &[&str] = &["SECTION", "BLOCK", "TABLE", "POLYLINE"];
             CONTAINER_TYPES.contains(&&*node.node_type)
@@ -96,20 +103,32 @@ impl<'a> NodeParser<'a> {
                         .iter()
                         .any(|a| a.code == 66 && a.value.parse() == Ok(1i16)))
         }
-        assert!(is_node_starting_code(self.atoms[start].code));
+        assert!(is_node_starting_atom(&self.atoms[start], in_header));
         let node_type = &self.atoms[start].value;
-        let (mut node, mut pos) = self.parse_element(node_type, start + 1)?;
+        // header variables are the only nodes started by code 9
+        let in_header = in_header
+            || (node_type == "SECTION"
+                && self
+                    .atoms
+                    .get(start + 1)
+                    .is_some_and(|a| a.code == 2 && a.value == "HEADER"));
+        let (mut node, mut pos) = self.parse_element(node_type, start + 1, in_header)?;
         if is_container_type(&node) {
-            let (nodes, end_node, end_pos) = self.parse_nodes(pos)?;
+            let (nodes, end_node, end_pos) = self.parse_nodes(pos, in_header)?;
             node.nodes = nodes;
             node.end = Some(Box::new(end_node));
             pos = end_pos;
         }
         Some((node, pos))
     }
-    fn parse_element(&self, node_type: &'a str, start: usize) -> Option<(Node<'a>, usize)> {
+    fn parse_element(
+        &self,
+        node_type: &'a str,
+        start: usize,
+        in_header: bool,
+    ) -> Option<(Node<'a>, usize)> {
         (start..self.atoms.len())
-            .find(|i| is_node_starting_code(self.atoms[*i].code))
+            .find(|i| is_node_starting_atom(&self.atoms[*i], in_header))
             .map(|end| {
                 let entity = Node {
                     node_type: Cow::Borrowed(node_type),
@@ -122,6 +141,8 @@ impl<'a> NodeParser<'a> {
     }
 }
 
-fn is_node_starting_code(code: i16) -> bool {
-    code == 0 || code == 9
+// code 9 starts a header variable in the HEADER section,
+// but holds the text of complex linetype elements elsewhere
+fn is_node_starting_atom(atom: &Atom, in_header: bool) -> bool {
+    atom.code == 0 || (in_header && atom.code == 9)
 }
@@ -0,0 +1,200 @@
+use super::SetAtom;
+use crate::{Atom, ModelerGeometry};
+use std::borrow::Cow;
+
+impl SetAtom for ModelerGeometry {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            70 => super::parse_to(value, &mut self.version),
+            1 => {
+                if !self.sat.is_empty() {
+                    self.sat.push('\n');
+                }
+                let text = unescape_caret(value);
+                // plain SAT text starts with the version number
+                self.is_encrypted = match text.chars().find(|&c| c != ' ') {
+                    Some(c) if self.sat.is_empty() => !c.is_ascii_digit(),
+                    _ => self.is_encrypted,
+                };
+                self.sat += &decode(&text, self.is_encrypted);
+                true
+            }
+            3 => {
+                self.sat += &decode(&unescape_caret(value), self.is_encrypted);
+                true
+            }
+            310 => super::hex_to_bytes(value, &mut self.sab),
+            _ => false,
+        }
+    }
+}
+
+impl ModelerGeometry {
+    /// Group codes 70, 1, 3 and 310 to write the ACIS data back to DXF.
+    /// The SAT text is encrypted again if it was encrypted in the source.
+    pub fn to_atoms(&self) -> Vec<Atom<'static>> {
+        let atom = |code, value: String| Atom {
+            code,
+            value: Cow::Owned(value),
+        };
+        let mut atoms = vec![atom(70, self.version.to_string())];
+        if !self.sat.is_empty() {
+            for line in self.sat.lines() {
+                let encoded = escape_caret(&encode(line, self.is_encrypted));
+                let mut chunks = split_at_char_boundaries(&encoded, 255).into_iter();
+                atoms.extend(chunks.next().map(|s| atom(1, s.to_owned())));
+                atoms.extend(chunks.map(|s| atom(3, s.to_owned())));
+            }
+        }
+        for chunk in self.sab.chunks(127) {
+            let hex = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+            atoms.push(atom(310, hex));
+        }
+        atoms
+    }
+}
+
+/// Attaches SAB data stored in the ACDSDATA section (R2013 and later) to the entities.
+pub(super) fn attach_acds_data(section: &crate::Node, drawing: &mut crate::Document) {
+    use crate::{Entity, EntityNode};
+    let mut records = std::collections::HashMap::new();
+    for record in section.nodes.iter().filter(|n| n.node_type == "ACDSRECORD") {
+        let mut handle = None;
+        let mut sab = Vec::new();
+        let mut name = "";
+        for atom in record.atoms.iter() {
+            match atom.code {
+                2 => name = &atom.value,
+                320 if name == "AcDbDs::ID" => handle = u32::from_str_radix(&atom.value, 16).ok(),
+                310 if name == "ASM_Data" => {
+                    super::hex_to_bytes(&atom.value, &mut sab);
+                }
+                _ => {}
+            }
+        }
+        if let Some(handle) = handle {
+            records.insert(handle, sab);
+        }
+    }
+    let entities = drawing.entities.iter_mut().chain(
+        drawing
+            .blocks
+            .iter_mut()
+            .flat_map(|b| b.entities.iter_mut()),
+    );
+    for EntityNode { header, entity } in entities {
+        match entity {
+            Entity::Region(geometry)
+            | Entity::Solid3d(geometry)
+            | Entity::Body(geometry)
+            | Entity::Surface(geometry) => {
+                if let Some(sab) = records.remove(&header.handle) {
+                    geometry.sab = sab;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// control characters are written as "^" followed by (c + 64), and "^" itself as "^ "
+fn unescape_caret(s: &str) -> String {
+    let mut dst = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '^' {
+            match chars.next() {
+                Some(' ') => dst.push('^'),
+                Some(c @ '@'..='_') => dst.push((c as u8 - 64) as char),
+                Some(c) => {
+                    dst.push('^');
+                    dst.push(c);
+                }
+                None => dst.push('^'),
+            }
+        } else {
+            dst.push(c);
+        }
+    }
+    dst
+}
+
+fn escape_caret(s: &str) -> String {
+    let mut dst = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '^' => dst += "^ ",
+            c if (c as u32) < 32 => {
+                dst.push('^');
+                dst.push((c as u8 + 64) as char);
+            }
+            c => dst.push(c),
+        }
+    }
+    dst
+}
+
+// The simple substitution applied by AutoCAD: every printable ASCII character except the space
+// becomes (159 - c). The values are already decoded from the document's codepage,
+// so other characters are kept as they are. The substitution is its own inverse.
+fn decode(s: &str, is_encrypted: bool) -> String {
+    s.chars()
+        .map(|c| match c {
+            '!'..='~' if is_encrypted => (159 - c as u8) as char,
+            c => c,
+        })
+        .collect()
+}
+
+fn encode(s: &str, is_encrypted: bool) -> String {
+    decode(s, is_encrypted)
+}
+
+fn split_at_char_boundaries(s: &str, max_len: usize) -> Vec<&str> {
+    let mut chunks = vec![];
+    let mut rest = s;
+    while rest.len() > max_len {
+        let mut k = max_len;
+        while !rest.is_char_boundary(k) {
+            k -= 1;
+        }
+        chunks.push(&rest[..k]);
+        rest = &rest[k..];
+    }
+    chunks.push(rest);
+    chunks
+}
+
+#[test]
+fn test_encryption() {
+    let sat = "700 0 1 0 \n@8 Autodesk @4 ^abc";
+    let src = ModelerGeometry {
+        version: 1,
+        sat: sat.to_owned(),
+        is_encrypted: true,
+        sab: vec![0x41, 0xCD, 0x00],
+    };
+    let atoms = src.to_atoms();
+    assert_eq!(&atoms[1].value, "hoo o n o ");
+    assert_eq!(&atoms[2].value, "_g ^ *+0;:,4 _k A>=<");
+    assert_eq!(&atoms.last().unwrap().value, "41CD00");
+    let mut dst = ModelerGeometry::default();
+    for atom in &atoms {
+        dst.set_atom(atom);
+    }
+    assert_eq!(dst, src);
+
+    // characters outside ASCII are kept through the substitution
+    let src = ModelerGeometry {
+        sat: "700 0 1 0 \n@6 Größe @6 名前".to_owned(),
+        ..src
+    };
+    let atoms = src.to_atoms();
+    assert!(atoms[2].value.contains("öß") && atoms[2].value.contains("名前"));
+    let mut dst = ModelerGeometry::default();
+    for atom in &atoms {
+        dst.set_atom(atom);
+    }
+    assert_eq!(dst, src);
+}
@@ -4,6 +4,7 @@ impl super::FromNode for BlockNode {
     fn from_node(source: &Node) -> Self {
         let mut target = Self {
             handle: 0,
+            block_record_handle: 0,
             layer: String::default(),
             block_name: String::default(),
             block_flags: BlockFlags::default(),
@@ -12,8 +13,24 @@ impl super::FromNode for BlockNode {
             description: String::default(),
             entities: Vec::new(),
         };
+        let mut in_group = false;
         for atom in source.atoms.iter() {
+            // skip application-defined groups such as {ACAD_REACTORS
+            if atom.code == 102 {
+                in_group = atom.value.starts_with('{');
+                continue;
+            } else if in_group {
+                continue;
+            }
             let _ = match atom.code {
+                5 => super::parse_and_then_to(&atom.value, &mut target.handle, |s: String| {
+                    u32::from_str_radix(&s, 16).ok()
+                }),
+                330 => super::parse_and_then_to(
+                    &atom.value,
+                    &mut target.block_record_handle,
+                    |s: String| u32::from_str_radix(&s, 16).ok(),
+                ),
                 8 => super::parse_to(&atom.value, &mut target.layer),
                 2 | 3 => super::parse_to(&atom.value, &mut target.block_name),
                 70 => super::parse_to(&atom.value, &mut target.block_flags),
@@ -48,3 +65,87 @@ impl std::str::FromStr for BlockFlags {
         })
     }
 }
+
+#[test]
+fn test_block_record() {
+    let dxf = "  0
+SECTION
+  2
+TABLES
+  0
+TABLE
+  2
+BLOCK_RECORD
+  5
+1
+  0
+BLOCK_RECORD
+  5
+1F
+  2
+Bolt
+340
+0
+ 70
+4
+280
+0
+281
+1
+310
+424D00
+  0
+ENDTAB
+  0
+ENDSEC
+  0
+SECTION
+  2
+BLOCKS
+  0
+BLOCK
+  5
+20
+102
+{ACAD_REACTORS
+330
+99
+102
+}
+330
+1F
+  8
+0
+  2
+Bolt
+ 70
+0
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+  3
+Bolt
+  1
+
+  0
+ENDBLK
+  5
+21
+  8
+0
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let block = &doc.blocks[0];
+    assert_eq!(block.handle, 0x20);
+    let record = doc.block_record(block).unwrap();
+    assert_eq!(record.insert_units, Units::Millimeters);
+    assert!(!record.is_explodable && record.is_scalable);
+    assert_eq!(record.layout_handle, None);
+    assert_eq!(record.preview, [0x42, 0x4D, 0x00]);
+}
@@ -22,10 +22,12 @@ impl FromStr for Space {
 impl FromStr for LineTypeRef {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "BYLAYER" => Self::ByLayer,
-            "BYBLOCK" => Self::ByBlock,
-            _ => LineTypeRef::ByName(s.to_owned()),
+        Ok(if s.eq_ignore_ascii_case("BYLAYER") {
+            Self::ByLayer
+        } else if s.eq_ignore_ascii_case("BYBLOCK") {
+            Self::ByBlock
+        } else {
+            LineTypeRef::ByName(s.to_owned())
         })
     }
 }
@@ -164,3 +166,324 @@ impl FromStr for MTextDirection {
         })
     }
 }
+
+impl FromStr for ImageDisplayFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            show_image: (flags & 0b0001) != 0,
+            show_image_when_not_aligned_with_screen: (flags & 0b0010) != 0,
+            use_clipping_boundary: (flags & 0b0100) != 0,
+            transparency_is_on: (flags & 0b1000) != 0,
+        })
+    }
+}
+
+impl FromStr for ImageClipBoundaryType {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            1 => Self::Rectangular,
+            2 => Self::Polygonal,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for ImageResolutionUnits {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::NoUnits,
+            2 => Self::Centimeters,
+            5 => Self::Inch,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for MLineJustification {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::Top,
+            1 => Self::Zero,
+            2 => Self::Bottom,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for MLineFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            has_vertex: (flags & 0b0001) != 0,
+            is_closed: (flags & 0b0010) != 0,
+            suppress_start_caps: (flags & 0b0100) != 0,
+            suppress_end_caps: (flags & 0b1000) != 0,
+        })
+    }
+}
+
+// DIMZIN, DIMTZIN, DIMALTZ and DIMALTTZ
+impl FromStr for ZeroSuppression {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            zero_feet: matches!(flags & 0b11, 0 | 3),
+            zero_inches: matches!(flags & 0b11, 0 | 2),
+            leading_zeros: (flags & 0b0100) != 0,
+            trailing_zeros: (flags & 0b1000) != 0,
+        })
+    }
+}
+
+impl FromStr for LinearUnitFormat {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            1 => Self::Scientific,
+            2 => Self::Decimal,
+            3 => Self::Engineering,
+            4 => Self::Architectural,
+            5 => Self::Fractional,
+            6 => Self::WindowsDesktop,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for AngularUnitFormat {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::DecimalDegrees,
+            1 => Self::DegreesMinutesSeconds,
+            2 => Self::Gradians,
+            3 => Self::Radians,
+            4 => Self::SurveyorsUnits,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for FractionFormat {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::HorizontalStacking,
+            1 => Self::DiagonalStacking,
+            2 => Self::NotStacked,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for DimensionTextMovement {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::MoveDimensionLineWithText,
+            1 => Self::AddLeader,
+            2 => Self::MoveTextFreely,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for DimensionTextJustification {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::Center,
+            1 => Self::NextToExtensionLine1,
+            2 => Self::NextToExtensionLine2,
+            3 => Self::AboveExtensionLine1,
+            4 => Self::AboveExtensionLine2,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for ToleranceAlignment {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::Bottom,
+            1 => Self::Middle,
+            2 => Self::Top,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for ArrowTextFit {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::TextAndArrowsOutside,
+            1 => Self::ArrowsFirst,
+            2 => Self::TextFirst,
+            3 => Self::BestFit,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for OrthographicType {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::NotOrthographic,
+            1 => Self::Top,
+            2 => Self::Bottom,
+            3 => Self::Front,
+            4 => Self::Back,
+            5 => Self::Left,
+            6 => Self::Right,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for Units {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Units::*;
+        Ok(match s.parse::<i16>()? {
+            0 => Unitless,
+            1 => Inches,
+            2 => Feet,
+            3 => Miles,
+            4 => Millimeters,
+            5 => Centimeters,
+            6 => Meters,
+            7 => Kilometers,
+            8 => Microinches,
+            9 => Mils,
+            10 => Yards,
+            11 => Angstroms,
+            12 => Nanometers,
+            13 => Microns,
+            14 => Decimeters,
+            15 => Decameters,
+            16 => Hectometers,
+            17 => Gigameters,
+            18 => AstronomicalUnits,
+            19 => LightYears,
+            20 => Parsecs,
+            21 => UsSurveyFeet,
+            22 => UsSurveyInches,
+            23 => UsSurveyYards,
+            24 => UsSurveyMiles,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for LayerFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            is_frozen: (flags & 0b0000_0001) != 0,
+            is_frozen_in_new_viewports: (flags & 0b0000_0010) != 0,
+            is_locked: (flags & 0b0000_0100) != 0,
+            is_xref_dependent: (flags & 0b0001_0000) != 0,
+            is_xref_resolved: (flags & 0b0010_0000) != 0,
+            is_referenced: (flags & 0b0100_0000) != 0,
+        })
+    }
+}
+
+impl FromStr for LineTypeElementFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            is_rotation_absolute: (flags & 0b001) != 0,
+            is_text: (flags & 0b010) != 0,
+            is_shape: (flags & 0b100) != 0,
+        })
+    }
+}
+
+impl FromStr for HatchStyle {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::Normal,
+            1 => Self::Outer,
+            2 => Self::Ignore,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for HatchPatternType {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i16>()? {
+            0 => Self::UserDefined,
+            1 => Self::Predefined,
+            2 => Self::Custom,
+            _ => return Err(parse_value_error::<Self>(s)),
+        })
+    }
+}
+
+impl FromStr for HatchBoundaryPathFlags {
+    type Err = <i32 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i32>().map(|flags| Self {
+            is_external: (flags & 0b00001) != 0,
+            is_polyline: (flags & 0b00010) != 0,
+            is_derived: (flags & 0b00100) != 0,
+            is_textbox: (flags & 0b01000) != 0,
+            is_outermost: (flags & 0b10000) != 0,
+        })
+    }
+}
+
+impl FromStr for PolylineFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            closed_polyline: (flags & 0b0000_0001) != 0,
+            curve_fit_vertices: (flags & 0b0000_0010) != 0,
+            spline_fit_vertices: (flags & 0b0000_0100) != 0,
+            polyline_3d: (flags & 0b0000_1000) != 0,
+            polygon_mesh_3d: (flags & 0b0001_0000) != 0,
+            closed_in_n_direction: (flags & 0b0010_0000) != 0,
+            polyface_mesh: (flags & 0b0100_0000) != 0,
+            continuous_linetype_pattern: (flags & 0b1000_0000) != 0,
+        })
+    }
+}
+
+impl FromStr for VertexFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            is_extra_vertex_by_curve_fitting: (flags & 0b0000_0001) != 0,
+            has_curve_fit_tangent: (flags & 0b0000_0010) != 0,
+            is_spline_fit_vertex: (flags & 0b0000_1000) != 0,
+            is_spline_frame_control_point: (flags & 0b0001_0000) != 0,
+            is_polyline_3d_vertex: (flags & 0b0010_0000) != 0,
+            is_polygon_mesh_vertex: (flags & 0b0100_0000) != 0,
+            is_polyface_mesh_vertex: (flags & 0b1000_0000) != 0,
+        })
+    }
+}
+
+impl FromStr for SplineFlags {
+    type Err = <i16 as FromStr>::Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i16>().map(|flags| Self {
+            is_closed: (flags & 0b00001) != 0,
+            is_periodic: (flags & 0b00010) != 0,
+            is_rational: (flags & 0b00100) != 0,
+            is_planar: (flags & 0b01000) != 0,
+            is_linear: (flags & 0b10000) != 0,
+        })
+    }
+}
@@ -5,7 +5,13 @@ impl FromNode for EntityNode {
     fn from_node(source: &Node) -> Self {
         match source.node_type.as_ref() {
             "INSERT" => parse_by(source, Entity::Insert),
-            "DIMENSION" => parse_by(source, Entity::Dimension),
+            "DIMENSION" => {
+                let mut node: EntityNode = parse_by(source, Entity::Dimension);
+                if let Entity::Dimension(dim) = &mut node.entity {
+                    dim.style_overrides = take_dstyle_overrides(&mut node.header.extras);
+                }
+                node
+            }
             "TEXT" => parse_by(source, Entity::Text),
             "MTEXT" => parse_by(source, |mut mtext: MText| {
                 mtext.text.parse_and_build_nodes();
@@ -15,7 +21,23 @@ impl FromNode for EntityNode {
             "LINE" => parse_by(source, Entity::Line),
             "CIRCLE" => parse_by(source, Entity::Circle),
             "ARC" => parse_by(source, Entity::Arc),
+            "ELLIPSE" => parse_by(source, Entity::Ellipse),
             "LWPOLYLINE" => parse_by(source, LwPolylineBuilder::into_entity),
+            "POLYLINE" => parse_by(source, Entity::Polyline),
+            "SPLINE" => parse_by(source, Entity::Spline),
+            "RAY" => parse_by(source, Entity::Ray),
+            "XLINE" => parse_by(source, Entity::XLine),
+            "IMAGE" => parse_by(source, Entity::Image),
+            "WIPEOUT" => parse_by(source, Entity::Wipeout),
+            "VIEWPORT" => parse_by(source, Entity::Viewport),
+            "MLINE" => parse_by(source, Entity::MLine),
+            "REGION" => parse_by(source, Entity::Region),
+            "3DSOLID" => parse_by(source, Entity::Solid3d),
+            "BODY" => parse_by(source, Entity::Body),
+            "SURFACE" | "EXTRUDEDSURFACE" | "LOFTEDSURFACE" | "REVOLVEDSURFACE"
+            | "SWEPTSURFACE" | "PLANESURFACE" | "NURBSURFACE" => parse_by(source, Entity::Surface),
+            "MESH" => MeshBuilder::from_node(source).into_entity_node(),
+            "HATCH" => parse_by(source, HatchBuilder::into_entity),
             _ => parse_by(source, |atoms| {
                 Entity::NotSupported((*source.node_type).to_owned(), atoms)
             }),
@@ -30,6 +52,51 @@ fn parse_by<T: SetAtom>(source: &Node, f: impl Fn(T) -> Entity) -> EntityNode {
     }
 }
 
+/// Removes the `ACAD` `DSTYLE` XDATA from `extras` and returns it as pairs of
+/// DIMSTYLE group codes and values.
+fn take_dstyle_overrides(extras: &mut Vec<Atom<'static>>) -> Vec<Atom<'static>> {
+    let app = match extras
+        .iter()
+        .position(|a| a.code == 1001 && a.value == "ACAD")
+    {
+        Some(app) => app,
+        None => return Vec::new(),
+    };
+    let app_end = extras[app + 1..]
+        .iter()
+        .position(|a| a.code == 1001)
+        .map_or(extras.len(), |k| app + 1 + k);
+    let start = match (app + 1..app_end).find(|&k| {
+        extras[k].code == 1000
+            && extras[k].value == "DSTYLE"
+            && extras
+                .get(k + 1)
+                .is_some_and(|a| a.code == 1002 && a.value == "{")
+    }) {
+        Some(start) => start,
+        None => return Vec::new(),
+    };
+    let mut overrides = Vec::new();
+    let mut k = start + 2;
+    while k + 1 < app_end && extras[k].code == 1070 {
+        if let Ok(code) = extras[k].value.parse() {
+            overrides.push(Atom {
+                code,
+                value: extras[k + 1].value.clone(),
+            });
+        }
+        k += 2;
+    }
+    if extras.get(k).is_some_and(|a| a.code == 1002) {
+        k += 1;
+    }
+    extras.drain(start..k);
+    if app + 1 == start && extras.get(app + 1).is_none_or(|a| a.code == 1001) {
+        extras.remove(app);
+    }
+    overrides
+}
+
 impl<T: SetAtom> SetAtom for (EntityHeader, T) {
     fn set_atom(&mut self, atom: &Atom) -> bool {
         if SetAtom::set_atom(&mut self.0, atom) || self.1.set_atom(atom) {
@@ -39,6 +106,9 @@ impl<T: SetAtom> SetAtom for (EntityHeader, T) {
             false
         }
     }
+    fn add_nodes(&mut self, nodes: &[Node]) -> bool {
+        self.1.add_nodes(nodes)
+    }
 }
 
 impl SetAtom for EntityHeader {
@@ -75,7 +145,7 @@ impl SetAtom for EntityHeader {
     }
 }
 
-impl<'a> SetAtom for Vec<Atom<'static>> {
+impl SetAtom for Vec<Atom<'static>> {
     fn set_atom(&mut self, atom: &Atom) -> bool {
         self.push(atom.clone().into_owned());
         true
@@ -359,6 +429,362 @@ impl SetAtom for Arc {
     }
 }
 
+impl SetAtom for Ellipse {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            10 => super::parse_to(value, &mut self.center[0]),
+            20 => super::parse_to(value, &mut self.center[1]),
+            30 => super::parse_to(value, &mut self.center[2]),
+            11 => super::parse_to(value, &mut self.major_axis[0]),
+            21 => super::parse_to(value, &mut self.major_axis[1]),
+            31 => super::parse_to(value, &mut self.major_axis[2]),
+            40 => super::parse_to(value, &mut self.axis_ratio),
+            41 => super::parse_to(value, &mut self.start_parameter),
+            42 => super::parse_to(value, &mut self.end_parameter),
+            210 => super::parse_optional_coord_to(value, 0, &mut self.extrusion_direction),
+            220 => super::parse_optional_coord_to(value, 1, &mut self.extrusion_direction),
+            230 => super::parse_optional_coord_to(value, 2, &mut self.extrusion_direction),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Polyline {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            66 => value.parse::<i16>().is_ok(),      // vertices follow
+            10 | 20 => value.parse::<f64>().is_ok(), // always 0
+            30 => super::parse_to_option(value, &mut self.elevation),
+            39 => super::parse_to_option(value, &mut self.thickness),
+            70 => super::parse_to(value, &mut self.flags),
+            40 => super::parse_to_option(value, &mut self.default_start_width),
+            41 => super::parse_to_option(value, &mut self.default_end_width),
+            71 => super::parse_to(value, &mut self.polygon_mesh_M_vertex_count),
+            72 => super::parse_to(value, &mut self.polygon_mesh_N_vertex_count),
+            73 => super::parse_to(value, &mut self.smooth_surface_M_density),
+            74 => super::parse_to(value, &mut self.smooth_surface_N_density),
+            75 => super::parse_and_then_to(value, &mut self.smooth_type, |x: i16| match x {
+                0 => Some(None),
+                5 => Some(Some(PolylineSmoothType::QuadraticBSpline)),
+                6 => Some(Some(PolylineSmoothType::CubicBSpline)),
+                8 => Some(Some(PolylineSmoothType::Bezier)),
+                _ => None,
+            }),
+            210 => super::parse_optional_coord_to(value, 0, &mut self.extrusion_direction),
+            220 => super::parse_optional_coord_to(value, 1, &mut self.extrusion_direction),
+            230 => super::parse_optional_coord_to(value, 2, &mut self.extrusion_direction),
+            _ => false,
+        }
+    }
+    fn add_nodes(&mut self, nodes: &[Node]) -> bool {
+        for node in nodes {
+            if node.node_type == "VERTEX" {
+                self.vertices.push(FromNode::from_node(node));
+            } else {
+                log::warn!("unexpected entity in POLYLINE: {}", node.node_type);
+            }
+        }
+        true
+    }
+}
+
+impl SetAtom for Vertex {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            10 => super::parse_to(value, &mut self.coord[0]),
+            20 => super::parse_to(value, &mut self.coord[1]),
+            30 => super::parse_to(value, &mut self.coord[2]),
+            40 => super::parse_to_option(value, &mut self.start_width),
+            41 => super::parse_to_option(value, &mut self.end_width),
+            42 => super::parse_to_option(value, &mut self.bulge),
+            70 => super::parse_to(value, &mut self.flags),
+            50 => super::parse_to(value, &mut self.curve_fit_tangent_direction),
+            // a negative index hides the edge starting at the vertex
+            71..=74 => match value.parse::<i32>() {
+                Ok(index) => {
+                    let indices = self.polyface_mesh_vertex_index.get_or_insert([0; 4]);
+                    indices[(atom.code - 71) as usize] = index.unsigned_abs() as usize;
+                    true
+                }
+                Err(_) => false,
+            },
+            91 => super::parse_to(value, &mut self.id),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Spline {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            210 => super::parse_optional_coord_to(value, 0, &mut self.normal),
+            220 => super::parse_optional_coord_to(value, 1, &mut self.normal),
+            230 => super::parse_optional_coord_to(value, 2, &mut self.normal),
+            70 => super::parse_to(value, &mut self.flags),
+            71 => super::parse_to(value, &mut self.degree),
+            // the numbers of knots, control points and fit points
+            72..=74 => value.parse::<usize>().is_ok(),
+            42 => super::parse_to_option(value, &mut self.knot_tolerance),
+            43 => super::parse_to_option(value, &mut self.control_point_tolerance),
+            44 => super::parse_to_option(value, &mut self.fit_tolerance),
+            12 => super::parse_optional_coord_to(value, 0, &mut self.start_tangent),
+            22 => super::parse_optional_coord_to(value, 1, &mut self.start_tangent),
+            32 => super::parse_optional_coord_to(value, 2, &mut self.start_tangent),
+            13 => super::parse_optional_coord_to(value, 0, &mut self.end_tangent),
+            23 => super::parse_optional_coord_to(value, 1, &mut self.end_tangent),
+            33 => super::parse_optional_coord_to(value, 2, &mut self.end_tangent),
+            40 => match value.parse() {
+                Ok(knot) => {
+                    self.knots.push(knot);
+                    true
+                }
+                Err(_) => false,
+            },
+            41 => match value.parse() {
+                Ok(weight) => {
+                    self.weights.push(weight);
+                    true
+                }
+                Err(_) => false,
+            },
+            10 | 11 => {
+                let points = if atom.code == 10 {
+                    &mut self.control_points
+                } else {
+                    &mut self.fit_points
+                };
+                let mut p = [0.0; 3];
+                let ok = super::parse_to(value, &mut p[0]);
+                points.push(p);
+                ok
+            }
+            20 | 30 | 21 | 31 => {
+                let points = if atom.code % 10 == 0 {
+                    &mut self.control_points
+                } else {
+                    &mut self.fit_points
+                };
+                match points.last_mut() {
+                    Some(p) => super::parse_to(value, &mut p[(atom.code / 10 - 1) as usize]),
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Ray {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            10 => super::parse_to(value, &mut self.base_point[0]),
+            20 => super::parse_to(value, &mut self.base_point[1]),
+            30 => super::parse_to(value, &mut self.base_point[2]),
+            11 => super::parse_to(value, &mut self.unit_direction[0]),
+            21 => super::parse_to(value, &mut self.unit_direction[1]),
+            31 => super::parse_to(value, &mut self.unit_direction[2]),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for XLine {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            10 => super::parse_to(value, &mut self.base_point[0]),
+            20 => super::parse_to(value, &mut self.base_point[1]),
+            30 => super::parse_to(value, &mut self.base_point[2]),
+            11 => super::parse_to(value, &mut self.unit_direction[0]),
+            21 => super::parse_to(value, &mut self.unit_direction[1]),
+            31 => super::parse_to(value, &mut self.unit_direction[2]),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Image {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            90 => super::parse_to(value, &mut self.class_version),
+            10 => super::parse_to(value, &mut self.insertion_point[0]),
+            20 => super::parse_to(value, &mut self.insertion_point[1]),
+            30 => super::parse_to(value, &mut self.insertion_point[2]),
+            11 => super::parse_to(value, &mut self.u_vector[0]),
+            21 => super::parse_to(value, &mut self.u_vector[1]),
+            31 => super::parse_to(value, &mut self.u_vector[2]),
+            12 => super::parse_to(value, &mut self.v_vector[0]),
+            22 => super::parse_to(value, &mut self.v_vector[1]),
+            32 => super::parse_to(value, &mut self.v_vector[2]),
+            13 => super::parse_to(value, &mut self.image_size[0]),
+            23 => super::parse_to(value, &mut self.image_size[1]),
+            340 => {
+                if let Ok(handle) = u32::from_str_radix(value, 16) {
+                    self.image_def_handle = handle;
+                    true
+                } else {
+                    false
+                }
+            }
+            70 => super::parse_to(value, &mut self.display_flags),
+            280 => super::parse_and_then_to(value, &mut self.is_clipping_on, |x: i16| Some(x != 0)),
+            281 => super::parse_to(value, &mut self.brightness),
+            282 => super::parse_to(value, &mut self.contrast),
+            283 => super::parse_to(value, &mut self.fade),
+            360 => {
+                self.image_def_reactor_handle = u32::from_str_radix(value, 16).ok();
+                self.image_def_reactor_handle.is_some()
+            }
+            71 => super::parse_to(value, &mut self.clip_boundary_type),
+            91 => value.parse::<usize>().is_ok(), // the number of vertices (implied by 14, 24)
+            14 => {
+                let mut x = 0.0;
+                let ok = super::parse_to(value, &mut x);
+                self.clip_boundary_vertices.push([x, 0.0]);
+                ok
+            }
+            24 => match self.clip_boundary_vertices.last_mut() {
+                Some(vertex) => super::parse_to(value, &mut vertex[1]),
+                None => false,
+            },
+            290 => {
+                super::parse_and_then_to(value, &mut self.is_inside_clipping, |x: i16| Some(x != 0))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Box<ViewportEntity> {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            10 => super::parse_to(value, &mut self.center[0]),
+            20 => super::parse_to(value, &mut self.center[1]),
+            30 => super::parse_to(value, &mut self.center[2]),
+            40 => super::parse_to(value, &mut self.width),
+            41 => super::parse_to(value, &mut self.height),
+            68 => super::parse_to(value, &mut self.status),
+            69 => super::parse_to(value, &mut self.id),
+            12 => super::parse_to(value, &mut self.view_center[0]),
+            22 => super::parse_to(value, &mut self.view_center[1]),
+            13 => super::parse_to(value, &mut self.snap_base_point[0]),
+            23 => super::parse_to(value, &mut self.snap_base_point[1]),
+            14 => super::parse_to(value, &mut self.snap_spacing[0]),
+            24 => super::parse_to(value, &mut self.snap_spacing[1]),
+            15 => super::parse_to(value, &mut self.grid_spacing[0]),
+            25 => super::parse_to(value, &mut self.grid_spacing[1]),
+            16 => super::parse_to(value, &mut self.view_direction[0]),
+            26 => super::parse_to(value, &mut self.view_direction[1]),
+            36 => super::parse_to(value, &mut self.view_direction[2]),
+            17 => super::parse_to(value, &mut self.view_target[0]),
+            27 => super::parse_to(value, &mut self.view_target[1]),
+            37 => super::parse_to(value, &mut self.view_target[2]),
+            42 => super::parse_to(value, &mut self.lens_length),
+            43 => super::parse_to(value, &mut self.front_clip_z),
+            44 => super::parse_to(value, &mut self.back_clip_z),
+            45 => super::parse_to(value, &mut self.view_height),
+            50 => super::parse_to(value, &mut self.snap_angle),
+            51 => super::parse_to(value, &mut self.twist_degree),
+            72 => super::parse_to(value, &mut self.circle_zoom_percent),
+            331 => {
+                if let Ok(handle) = u32::from_str_radix(value, 16) {
+                    self.frozen_layer_handles.push(handle);
+                    true
+                } else {
+                    false
+                }
+            }
+            90 => super::parse_to(value, &mut self.status_flags),
+            340 => {
+                self.clip_boundary_handle = u32::from_str_radix(value, 16).ok();
+                self.clip_boundary_handle.is_some()
+            }
+            1 => super::parse_to(value, &mut self.plot_style_sheet),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for MLine {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            2 => super::parse_to(value, &mut self.style_name),
+            340 => {
+                if let Ok(handle) = u32::from_str_radix(value, 16) {
+                    self.style_handle = handle;
+                    true
+                } else {
+                    false
+                }
+            }
+            40 => super::parse_to(value, &mut self.scale_factor),
+            70 => super::parse_to(value, &mut self.justification),
+            71 => super::parse_to(value, &mut self.flags),
+            72 | 73 => value.parse::<usize>().is_ok(), // the numbers of vertices and elements
+            10 => super::parse_to(value, &mut self.start_point[0]),
+            20 => super::parse_to(value, &mut self.start_point[1]),
+            30 => super::parse_to(value, &mut self.start_point[2]),
+            210 => super::parse_optional_coord_to(value, 0, &mut self.extrusion_direction),
+            220 => super::parse_optional_coord_to(value, 1, &mut self.extrusion_direction),
+            230 => super::parse_optional_coord_to(value, 2, &mut self.extrusion_direction),
+            11 => {
+                let mut vertex = MLineVertex::default();
+                let ok = super::parse_to(value, &mut vertex.coord[0]);
+                self.vertices.push(vertex);
+                ok
+            }
+            21 | 31 | 12 | 22 | 32 | 13 | 23 | 33 => {
+                if let Some(vertex) = self.vertices.last_mut() {
+                    let i = (atom.code / 10 - 1) as usize;
+                    let dst = match atom.code % 10 {
+                        1 => &mut vertex.coord[i],
+                        2 => &mut vertex.direction[i],
+                        _ => &mut vertex.miter_direction[i],
+                    };
+                    super::parse_to(value, dst)
+                } else {
+                    false
+                }
+            }
+            74 => {
+                if let Some(vertex) = self.vertices.last_mut() {
+                    vertex.elements.push(MLineSegmentElement::default());
+                    value.parse::<usize>().is_ok()
+                } else {
+                    false
+                }
+            }
+            41 | 42 => {
+                let element = self
+                    .vertices
+                    .last_mut()
+                    .and_then(|vertex| vertex.elements.last_mut());
+                match (element, value.parse()) {
+                    (Some(element), Ok(x)) => {
+                        if atom.code == 41 {
+                            element.parameters.push(x);
+                        } else {
+                            element.area_fill_parameters.push(x);
+                        }
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            75 => value.parse::<usize>().is_ok(), // the number of area fill parameters
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq)]
 struct LwPolylineVertexFlags {
     x: bool,
@@ -438,3 +864,1087 @@ impl SetAtom for LwPolylineBuilder {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum MeshList {
+    #[default]
+    None,
+    Faces,
+    Edges,
+    Creases,
+    Overrides, // sub-entity property overrides after the creases, left in the extras
+}
+// The header is read here rather than through `parse_by`, because the sub-entity
+// overrides reuse header codes such as 62 and 440 that must not reach the header.
+#[derive(Default)]
+struct MeshBuilder {
+    header: EntityHeader,
+    target: Mesh,
+    list: MeshList,
+    face_list: Vec<usize>,
+    edge_list: Vec<usize>,
+}
+impl MeshBuilder {
+    fn into_entity_node(mut self) -> EntityNode {
+        let mut items = self.face_list.into_iter();
+        while let Some(n) = items.next() {
+            self.target.faces.push(items.by_ref().take(n).collect());
+        }
+        self.target.edges = self
+            .edge_list
+            .chunks_exact(2)
+            .map(|pair| [pair[0], pair[1]])
+            .collect();
+        EntityNode {
+            header: self.header,
+            entity: Entity::Mesh(self.target),
+        }
+    }
+
+    fn set_mesh_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            71 => super::parse_to(value, &mut self.target.version),
+            72 => super::parse_and_then_to(value, &mut self.target.blend_crease, |x: i16| {
+                Some(x != 0)
+            }),
+            91 => super::parse_to(value, &mut self.target.subdivision_level),
+            92 => value.parse::<usize>().is_ok(), // the number of vertices
+            10 => {
+                let mut vertex = [0.0, 0.0, 0.0];
+                let ok = super::parse_to(value, &mut vertex[0]);
+                self.target.vertices.push(vertex);
+                ok
+            }
+            20 | 30 => match self.target.vertices.last_mut() {
+                Some(vertex) => super::parse_to(value, &mut vertex[(atom.code / 10 - 1) as usize]),
+                None => false,
+            },
+            93 => {
+                self.list = MeshList::Faces;
+                value.parse::<usize>().is_ok()
+            }
+            94 => {
+                self.list = MeshList::Edges;
+                value.parse::<usize>().is_ok()
+            }
+            95 => {
+                self.list = MeshList::Creases;
+                value.parse::<usize>().is_ok()
+            }
+            90 => {
+                let dst = match self.list {
+                    MeshList::Faces => &mut self.face_list,
+                    MeshList::Edges => &mut self.edge_list,
+                    MeshList::Creases => {
+                        // the number of sub-entities with overridden properties
+                        self.list = MeshList::Overrides;
+                        return false;
+                    }
+                    MeshList::None | MeshList::Overrides => return false,
+                };
+                if let Ok(i) = value.parse() {
+                    dst.push(i);
+                    true
+                } else {
+                    false
+                }
+            }
+            140 => {
+                if let Ok(crease) = value.parse() {
+                    self.target.creases.push(crease);
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+}
+impl SetAtom for MeshBuilder {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        if self.list != MeshList::Overrides
+            && (SetAtom::set_atom(&mut self.header, atom) || self.set_mesh_atom(atom))
+        {
+            true
+        } else {
+            self.header.extras.push(atom.clone().into_owned());
+            false
+        }
+    }
+}
+
+// the part of a HATCH the group codes are read into
+#[derive(Default, PartialEq)]
+enum HatchSection {
+    #[default]
+    Top,
+    Path,
+    Pattern,
+    SeedPoints,
+}
+#[derive(Default)]
+struct HatchBuilder {
+    target: Hatch,
+    section: HatchSection,
+}
+impl HatchBuilder {
+    fn into_entity(self) -> Entity {
+        Entity::Hatch(Box::new(self.target))
+    }
+    fn set_path_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        let path = match self.target.boundary_paths.last_mut() {
+            Some(path) => path,
+            None => return false,
+        };
+        if atom.code == 330 {
+            let mut handle = 0;
+            let ok = super::parse_and_then_to(value, &mut handle, |s: String| {
+                u32::from_str_radix(&s, 16).ok()
+            });
+            if ok {
+                path.source_handles.push(handle);
+            }
+            return ok;
+        }
+        match &mut path.boundary {
+            HatchBoundary::Polyline(polyline) => match atom.code {
+                72 | 93 => value.parse::<i16>().is_ok(), // has bulge flag, number of vertices
+                73 => {
+                    super::parse_and_then_to(value, &mut polyline.is_closed, |x: i16| Some(x != 0))
+                }
+                10 => {
+                    let mut vertex = LwPolylineVertex::default();
+                    let ok = super::parse_to(value, &mut vertex.coord[0]);
+                    polyline.vertices.push(vertex);
+                    ok
+                }
+                20 => match polyline.vertices.last_mut() {
+                    Some(vertex) => super::parse_to(value, &mut vertex.coord[1]),
+                    None => false,
+                },
+                42 => match polyline.vertices.last_mut() {
+                    Some(vertex) => super::parse_to_option(value, &mut vertex.bulge),
+                    None => false,
+                },
+                97 => value.parse::<i32>().is_ok(), // number of source objects
+                _ => false,
+            },
+            HatchBoundary::Edges(edges) => {
+                if atom.code == 72 {
+                    let edge = match value.parse::<i16>() {
+                        Ok(1) => HatchEdge::Line {
+                            start: [0.0, 0.0],
+                            end: [0.0, 0.0],
+                        },
+                        Ok(2) => HatchEdge::CircularArc {
+                            center: [0.0, 0.0],
+                            radius: 0.0,
+                            start_degree: 0.0,
+                            end_degree: 0.0,
+                            is_counterclockwise: true,
+                        },
+                        Ok(3) => HatchEdge::EllipticArc {
+                            center: [0.0, 0.0],
+                            major_axis: [1.0, 0.0],
+                            axis_ratio: 1.0,
+                            start_degree: 0.0,
+                            end_degree: 0.0,
+                            is_counterclockwise: true,
+                        },
+                        Ok(4) => HatchEdge::Spline(HatchSplineEdge::default()),
+                        _ => return false,
+                    };
+                    edges.push(edge);
+                    return true;
+                }
+                if atom.code == 93 || atom.code == 97 {
+                    // number of edges, of spline fit points or of source objects
+                    return value.parse::<i32>().is_ok();
+                }
+                let parse_ccw =
+                    |dst: &mut bool| super::parse_and_then_to(value, dst, |x: i16| Some(x != 0));
+                match edges.last_mut() {
+                    Some(HatchEdge::Line { start, end }) => match atom.code {
+                        10 => super::parse_to(value, &mut start[0]),
+                        20 => super::parse_to(value, &mut start[1]),
+                        11 => super::parse_to(value, &mut end[0]),
+                        21 => super::parse_to(value, &mut end[1]),
+                        _ => false,
+                    },
+                    Some(HatchEdge::CircularArc {
+                        center,
+                        radius,
+                        start_degree,
+                        end_degree,
+                        is_counterclockwise,
+                    }) => match atom.code {
+                        10 => super::parse_to(value, &mut center[0]),
+                        20 => super::parse_to(value, &mut center[1]),
+                        40 => super::parse_to(value, radius),
+                        50 => super::parse_to(value, start_degree),
+                        51 => super::parse_to(value, end_degree),
+                        73 => parse_ccw(is_counterclockwise),
+                        _ => false,
+                    },
+                    Some(HatchEdge::EllipticArc {
+                        center,
+                        major_axis,
+                        axis_ratio,
+                        start_degree,
+                        end_degree,
+                        is_counterclockwise,
+                    }) => match atom.code {
+                        10 => super::parse_to(value, &mut center[0]),
+                        20 => super::parse_to(value, &mut center[1]),
+                        11 => super::parse_to(value, &mut major_axis[0]),
+                        21 => super::parse_to(value, &mut major_axis[1]),
+                        40 => super::parse_to(value, axis_ratio),
+                        50 => super::parse_to(value, start_degree),
+                        51 => super::parse_to(value, end_degree),
+                        73 => parse_ccw(is_counterclockwise),
+                        _ => false,
+                    },
+                    Some(HatchEdge::Spline(spline)) => {
+                        let push = |list: &mut Vec<[f64; 2]>| {
+                            let mut p = [0.0, 0.0];
+                            let ok = super::parse_to(value, &mut p[0]);
+                            list.push(p);
+                            ok
+                        };
+                        let set_y = |list: &mut Vec<[f64; 2]>| match list.last_mut() {
+                            Some(p) => super::parse_to(value, &mut p[1]),
+                            None => false,
+                        };
+                        let set_tangent = |dst: &mut Option<[f64; 2]>, i: usize| {
+                            let mut tangent = dst.unwrap_or_default();
+                            let ok = super::parse_to(value, &mut tangent[i]);
+                            *dst = Some(tangent);
+                            ok
+                        };
+                        match atom.code {
+                            94 => super::parse_to(value, &mut spline.degree),
+                            73 => super::parse_and_then_to(
+                                value,
+                                &mut spline.is_rational,
+                                |x: i16| Some(x != 0),
+                            ),
+                            74 => super::parse_and_then_to(
+                                value,
+                                &mut spline.is_periodic,
+                                |x: i16| Some(x != 0),
+                            ),
+                            95 | 96 => value.parse::<i32>().is_ok(), // numbers of knots and control points
+                            40 => match value.parse() {
+                                Ok(knot) => {
+                                    spline.knots.push(knot);
+                                    true
+                                }
+                                Err(_) => false,
+                            },
+                            42 => match value.parse() {
+                                Ok(weight) => {
+                                    spline.weights.push(weight);
+                                    true
+                                }
+                                Err(_) => false,
+                            },
+                            10 => push(&mut spline.control_points),
+                            20 => set_y(&mut spline.control_points),
+                            11 => push(&mut spline.fit_points),
+                            21 => set_y(&mut spline.fit_points),
+                            12 => set_tangent(&mut spline.start_tangent, 0),
+                            22 => set_tangent(&mut spline.start_tangent, 1),
+                            13 => set_tangent(&mut spline.end_tangent, 0),
+                            23 => set_tangent(&mut spline.end_tangent, 1),
+                            _ => false,
+                        }
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+}
+impl SetAtom for HatchBuilder {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            91 => {
+                self.section = HatchSection::Path;
+                return value.parse::<i32>().is_ok(); // number of boundary paths
+            }
+            92 if self.section == HatchSection::Path => {
+                let mut path = HatchBoundaryPath::default();
+                let ok = super::parse_to(value, &mut path.flags);
+                if path.flags.is_polyline {
+                    path.boundary = HatchBoundary::Polyline(LwPolyline::default());
+                }
+                self.target.boundary_paths.push(path);
+                return ok;
+            }
+            75 => {
+                self.section = HatchSection::Top;
+                return super::parse_to(value, &mut self.target.style);
+            }
+            78 => {
+                self.section = HatchSection::Pattern;
+                return value.parse::<i32>().is_ok(); // number of pattern definition lines
+            }
+            98 => {
+                self.section = HatchSection::SeedPoints;
+                return value.parse::<i32>().is_ok(); // number of seed points
+            }
+            _ => {}
+        }
+        match self.section {
+            HatchSection::Path => self.set_path_atom(atom),
+            HatchSection::Pattern if matches!(atom.code, 53 | 43 | 44 | 45 | 46 | 79 | 49) => {
+                if atom.code == 53 {
+                    self.target.pattern_lines.push(HatchPatternLine::default());
+                }
+                let line = match self.target.pattern_lines.last_mut() {
+                    Some(line) => line,
+                    None => return false,
+                };
+                match atom.code {
+                    53 => super::parse_to(value, &mut line.angle_degree),
+                    43 => super::parse_to(value, &mut line.base_point[0]),
+                    44 => super::parse_to(value, &mut line.base_point[1]),
+                    45 => super::parse_to(value, &mut line.offset[0]),
+                    46 => super::parse_to(value, &mut line.offset[1]),
+                    79 => value.parse::<i16>().is_ok(), // number of dash lengths
+                    _ => match value.parse() {
+                        Ok(length) => {
+                            line.dash_lengths.push(length);
+                            true
+                        }
+                        Err(_) => false,
+                    },
+                }
+            }
+            HatchSection::SeedPoints if matches!(atom.code, 10 | 20) => {
+                if atom.code == 10 {
+                    self.target.seed_points.push([0.0, 0.0]);
+                }
+                match self.target.seed_points.last_mut() {
+                    Some(p) => super::parse_to(value, &mut p[(atom.code / 10 - 1) as usize]),
+                    None => false,
+                }
+            }
+            _ => match atom.code {
+                10 => super::parse_to(value, &mut self.target.elevation_point[0]),
+                20 => super::parse_to(value, &mut self.target.elevation_point[1]),
+                30 => super::parse_to(value, &mut self.target.elevation_point[2]),
+                210 => {
+                    super::parse_optional_coord_to(value, 0, &mut self.target.extrusion_direction)
+                }
+                220 => {
+                    super::parse_optional_coord_to(value, 1, &mut self.target.extrusion_direction)
+                }
+                230 => {
+                    super::parse_optional_coord_to(value, 2, &mut self.target.extrusion_direction)
+                }
+                2 => super::parse_to(value, &mut self.target.pattern_name),
+                70 => super::parse_and_then_to(value, &mut self.target.is_solid_fill, |x: i16| {
+                    Some(x != 0)
+                }),
+                71 => super::parse_and_then_to(value, &mut self.target.is_associative, |x: i16| {
+                    Some(x != 0)
+                }),
+                76 => super::parse_to(value, &mut self.target.pattern_type),
+                52 => super::parse_to(value, &mut self.target.pattern_angle_degree),
+                41 => super::parse_to(value, &mut self.target.pattern_scale),
+                77 => {
+                    super::parse_and_then_to(value, &mut self.target.is_pattern_double, |x: i16| {
+                        Some(x != 0)
+                    })
+                }
+                47 => super::parse_to_option(value, &mut self.target.pixel_size),
+                _ => false,
+            },
+        }
+    }
+}
+
+#[test]
+fn test_dstyle_overrides() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+DIMENSION
+  5
+30
+  8
+0
+  3
+Standard
+1001
+ACAD
+1000
+DSTYLE
+1002
+{
+1070
+271
+1070
+3
+1070
+40
+1040
+2.0
+1070
+278
+1070
+0
+1002
+}
+1001
+OTHER
+1000
+kept
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let node = &doc.entities[0];
+    let dim = match &node.entity {
+        Entity::Dimension(dim) => dim,
+        _ => panic!("not a dimension"),
+    };
+    assert_eq!(dim.style_overrides.len(), 3);
+    assert_eq!(node.header.extras.len(), 2);
+    let mut style = DimStyle {
+        decimal_places: 1,
+        decimal_separator: Some(','),
+        ..Default::default()
+    };
+    style.apply_overrides(&dim.style_overrides);
+    assert_eq!(style.decimal_places, 3);
+    assert_eq!(style.scale_factor, 2.0);
+    assert_eq!(style.decimal_separator, None);
+}
+
+#[test]
+fn test_hatch() {
+    let pairs = [
+        ("0", "SECTION"),
+        ("2", "ENTITIES"),
+        ("0", "HATCH"),
+        ("5", "2A"),
+        ("330", "1F"),
+        ("8", "0"),
+        ("10", "0.0"),
+        ("20", "0.0"),
+        ("30", "1.5"),
+        ("2", "ANSI31"),
+        ("70", "0"),
+        ("71", "1"),
+        ("91", "2"),
+        // polyline path
+        ("92", "3"),
+        ("72", "1"),
+        ("73", "1"),
+        ("93", "3"),
+        ("10", "0.0"),
+        ("20", "0.0"),
+        ("42", "0.0"),
+        ("10", "10.0"),
+        ("20", "0.0"),
+        ("42", "1.0"),
+        ("10", "10.0"),
+        ("20", "10.0"),
+        ("42", "0.0"),
+        ("97", "1"),
+        ("330", "3B"),
+        // edge path: a clockwise circle
+        ("92", "16"),
+        ("93", "1"),
+        ("72", "2"),
+        ("10", "5.0"),
+        ("20", "5.0"),
+        ("40", "1.0"),
+        ("50", "0.0"),
+        ("51", "360.0"),
+        ("73", "0"),
+        ("97", "0"),
+        ("75", "1"),
+        ("76", "1"),
+        ("52", "0.0"),
+        ("41", "1.0"),
+        ("77", "0"),
+        ("78", "1"),
+        ("53", "45.0"),
+        ("43", "0.0"),
+        ("44", "0.0"),
+        ("45", "-2.2"),
+        ("46", "2.2"),
+        ("79", "2"),
+        ("49", "1.0"),
+        ("49", "-0.5"),
+        ("98", "1"),
+        ("10", "2.0"),
+        ("20", "3.0"),
+        ("0", "ENDSEC"),
+        ("0", "EOF"),
+    ];
+    let dxf = pairs
+        .iter()
+        .map(|(code, value)| format!("{:>3}\n{}", code, value))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let doc = crate::Document::parse_str(&dxf).unwrap();
+    let hatch = match &doc.entities[0].entity {
+        Entity::Hatch(hatch) => hatch,
+        _ => panic!("not a hatch"),
+    };
+    assert_eq!(hatch.elevation_point, [0.0, 0.0, 1.5]);
+    assert_eq!(hatch.pattern_name, "ANSI31");
+    assert!(hatch.is_associative);
+    assert_eq!(hatch.boundary_paths.len(), 2);
+    let path = &hatch.boundary_paths[0];
+    assert!(path.flags.is_external && path.flags.is_polyline);
+    assert_eq!(path.source_handles, vec![0x3B]);
+    match &path.boundary {
+        HatchBoundary::Polyline(polyline) => {
+            assert!(polyline.is_closed);
+            assert_eq!(polyline.vertices.len(), 3);
+            assert_eq!(polyline.vertices[1].bulge, Some(1.0));
+        }
+        _ => panic!("not a polyline path"),
+    }
+    assert_eq!(
+        hatch.boundary_paths[1].boundary,
+        HatchBoundary::Edges(vec![HatchEdge::CircularArc {
+            center: [5.0, 5.0],
+            radius: 1.0,
+            start_degree: 0.0,
+            end_degree: 360.0,
+            is_counterclockwise: false,
+        }])
+    );
+    assert_eq!(hatch.style, HatchStyle::Outer);
+    assert_eq!(hatch.pattern_type, HatchPatternType::Predefined);
+    assert_eq!(
+        hatch.pattern_lines,
+        vec![HatchPatternLine {
+            angle_degree: 45.0,
+            base_point: [0.0, 0.0],
+            offset: [-2.2, 2.2],
+            dash_lengths: vec![1.0, -0.5],
+        }]
+    );
+    assert_eq!(hatch.seed_points, vec![[2.0, 3.0]]);
+    assert_eq!(doc.entities[0].header.extras.len(), 1);
+}
+
+#[test]
+fn test_mline() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+MLINE
+  5
+2E
+  8
+0
+100
+AcDbMline
+  2
+TWO
+340
+2F
+ 40
+2.0
+ 70
+1
+ 71
+3
+ 72
+2
+ 73
+2
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 11
+0.0
+ 21
+0.0
+ 31
+0.0
+ 12
+1.0
+ 22
+0.0
+ 32
+0.0
+ 13
+0.0
+ 23
+1.0
+ 33
+0.0
+ 74
+2
+ 41
+0.0
+ 41
+5.0
+ 75
+0
+ 74
+1
+ 41
+-1.0
+ 75
+0
+ 11
+10.0
+ 21
+0.0
+ 31
+0.0
+ 12
+-1.0
+ 22
+0.0
+ 32
+0.0
+ 13
+0.0
+ 23
+1.0
+ 33
+0.0
+ 74
+1
+ 41
+0.0
+ 75
+0
+ 74
+1
+ 41
+-1.0
+ 75
+0
+  0
+ENDSEC
+  0
+SECTION
+  2
+OBJECTS
+  0
+MLINESTYLE
+  5
+2F
+  2
+TWO
+ 70
+0
+  3
+Two lines
+ 62
+256
+ 51
+90.0
+ 52
+90.0
+ 71
+2
+ 49
+0.5
+ 62
+1
+  6
+BYLAYER
+ 49
+-0.5
+ 62
+5
+  6
+DASHED
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let mline = match &doc.entities[0].entity {
+        Entity::MLine(mline) => mline,
+        _ => panic!("not a multiline"),
+    };
+    assert_eq!(mline.style_name, "TWO");
+    assert_eq!(mline.style_handle, 0x2F);
+    assert_eq!(mline.scale_factor, 2.0);
+    assert_eq!(mline.justification, MLineJustification::Zero);
+    assert!(mline.flags.has_vertex && mline.flags.is_closed);
+    assert_eq!(mline.vertices.len(), 2);
+    let vertex = &mline.vertices[1];
+    assert_eq!(vertex.coord, [10.0, 0.0, 0.0]);
+    assert_eq!(vertex.direction, [-1.0, 0.0, 0.0]);
+    assert_eq!(vertex.miter_direction, [0.0, 1.0, 0.0]);
+    assert_eq!(mline.vertices[0].elements.len(), 2);
+    assert_eq!(mline.vertices[0].elements[0].parameters, [0.0, 5.0]);
+    assert_eq!(mline.vertices[0].elements[1].parameters, [-1.0]);
+    assert_eq!(vertex.elements[1].parameters, [-1.0]);
+
+    let style = doc.mline_style(mline).unwrap();
+    assert_eq!(style.description, "Two lines");
+    assert_eq!(style.fill_color, ColorNumber::ByLayer);
+    assert_eq!(
+        (style.start_angle_degree, style.end_angle_degree),
+        (90.0, 90.0)
+    );
+    assert_eq!(style.elements.len(), 2);
+    assert_eq!(style.elements[0].offset, 0.5);
+    assert_eq!(style.elements[0].color, ColorNumber::Number(1));
+    assert_eq!(style.elements[0].line_type, LineTypeRef::ByLayer);
+    assert_eq!(style.elements[1].offset, -0.5);
+    assert_eq!(
+        style.elements[1].line_type,
+        LineTypeRef::ByName("DASHED".to_owned())
+    );
+}
+
+#[test]
+fn test_mesh_overrides() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+MESH
+  5
+30
+  8
+0
+ 62
+1
+ 71
+2
+ 72
+0
+ 91
+3
+ 92
+3
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 10
+1.0
+ 20
+0.0
+ 30
+0.0
+ 10
+0.0
+ 20
+1.0
+ 30
+0.0
+ 93
+4
+ 90
+3
+ 90
+0
+ 90
+1
+ 90
+2
+ 94
+0
+ 95
+0
+ 90
+1
+ 91
+0
+ 92
+2
+ 90
+0
+ 62
+5
+ 90
+1
+347
+4F
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let node = &doc.entities[0];
+    let mesh = match &node.entity {
+        Entity::Mesh(mesh) => mesh,
+        _ => panic!("not a mesh"),
+    };
+    assert_eq!(mesh.subdivision_level, 3);
+    assert_eq!(mesh.vertices.len(), 3);
+    assert_eq!(mesh.faces, [vec![0, 1, 2]]);
+    assert!(mesh.edges.is_empty() && mesh.creases.is_empty());
+    let extras = node
+        .header
+        .extras
+        .iter()
+        .map(|atom| (atom.code, &*atom.value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        extras,
+        [
+            (90, "1"),
+            (91, "0"),
+            (92, "2"),
+            (90, "0"),
+            (62, "5"),
+            (90, "1"),
+            (347, "4F")
+        ]
+    );
+    assert_eq!(node.header.color_number, ColorNumber::Number(1));
+}
+
+#[test]
+fn test_polyline_and_spline() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+POLYLINE
+  5
+30
+  8
+0
+ 66
+1
+ 10
+0.0
+ 20
+0.0
+ 30
+1.0
+ 70
+3
+  0
+VERTEX
+  5
+31
+  8
+0
+ 10
+0.0
+ 20
+0.0
+ 30
+1.0
+ 42
+1.0
+  0
+VERTEX
+  5
+32
+  8
+0
+ 10
+2.0
+ 20
+0.0
+ 30
+1.0
+ 70
+1
+  0
+VERTEX
+  5
+33
+  8
+0
+ 10
+2.0
+ 20
+2.0
+ 30
+1.0
+  0
+SEQEND
+  5
+34
+  8
+0
+  0
+POLYLINE
+  5
+40
+  8
+0
+ 66
+1
+ 70
+12
+ 75
+5
+  0
+VERTEX
+  5
+41
+  8
+0
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 70
+48
+  0
+VERTEX
+  5
+42
+  8
+0
+ 10
+1.0
+ 20
+2.0
+ 30
+0.0
+ 70
+48
+  0
+VERTEX
+  5
+43
+  8
+0
+ 10
+2.0
+ 20
+0.0
+ 30
+0.0
+ 70
+48
+  0
+SEQEND
+  5
+44
+  8
+0
+  0
+SPLINE
+  5
+50
+  8
+0
+210
+0.0
+220
+0.0
+230
+1.0
+ 70
+8
+ 71
+2
+ 72
+6
+ 73
+3
+ 74
+0
+ 40
+0.0
+ 40
+0.0
+ 40
+0.0
+ 40
+1.0
+ 40
+1.0
+ 40
+1.0
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 10
+1.0
+ 20
+-2.0
+ 30
+0.0
+ 10
+2.0
+ 20
+0.0
+ 30
+0.0
+  0
+ENDSEC
+  0
+EOF";
+    let doc = crate::Document::parse_str(dxf).unwrap();
+    let resolution = Resolution::SegmentsPerTurn(16);
+    let polyline = match &doc.entities[0].entity {
+        Entity::Polyline(polyline) => polyline,
+        _ => panic!("not a polyline"),
+    };
+    assert!(polyline.flags.closed_polyline && polyline.flags.curve_fit_vertices);
+    assert_eq!(polyline.elevation, Some(1.0));
+    assert_eq!(polyline.vertices.len(), 3);
+    assert!(polyline.vertices[1].flags.is_extra_vertex_by_curve_fitting);
+    assert_eq!(polyline.vertices[0].bulge, Some(1.0));
+    let points = polyline.tessellate(&resolution);
+    assert_eq!(points.first(), Some(&[0.0, 0.0, 1.0]));
+    assert_eq!(points.last(), Some(&[0.0, 0.0, 1.0]));
+    let bbox = doc.entity_bounding_box(&doc.entities[0].entity);
+    assert!((bbox.min[1] + 1.0).abs() < 1e-9 && bbox.max == [2.0, 2.0, 1.0]);
+
+    // a spline-fit polyline stored as its frame only
+    let frame = match &doc.entities[1].entity {
+        Entity::Polyline(polyline) => polyline,
+        _ => panic!("not a polyline"),
+    };
+    assert!(frame.is_polyline_3d() && frame.flags.spline_fit_vertices);
+    assert_eq!(
+        frame.smooth_type,
+        Some(PolylineSmoothType::QuadraticBSpline)
+    );
+    assert!(frame.vertices[2].flags.is_spline_frame_control_point);
+    let points = frame.tessellate(&resolution);
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[2], [1.0, 1.0, 0.0]);
+
+    let spline = match &doc.entities[2].entity {
+        Entity::Spline(spline) => spline,
+        _ => panic!("not a spline"),
+    };
+    assert!(spline.flags.is_planar && !spline.flags.is_rational);
+    assert_eq!(spline.degree, 2);
+    assert_eq!(spline.knots.len(), 6);
+    assert_eq!(spline.control_points[1], [1.0, -2.0, 0.0]);
+    assert_eq!(spline.normal, Some([0.0, 0.0, 1.0]));
+    let bbox = doc.entity_bounding_box(&doc.entities[2].entity);
+    assert!((bbox.min[1] + 1.0).abs() < 1e-9 && bbox.max == [2.0, 0.0, 0.0]);
+}
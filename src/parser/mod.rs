@@ -1,6 +1,8 @@
+mod acis;
 mod block;
 mod data;
 mod entity;
+mod object;
 mod table;
 mod text_format;
 
@@ -14,7 +16,9 @@ impl Document {
             tables: Vec::new(),
             blocks: Vec::new(),
             entities: Vec::new(),
+            objects: Vec::new(),
         };
+        let mut acds_data = None;
         for section in nodes {
             match section.atoms.find(2) {
                 Some("HEADER") => {
@@ -30,7 +34,10 @@ impl Document {
                 Some("ENTITIES") => {
                     drawing.entities = section.nodes.iter().map(FromNode::from_node).collect();
                 }
-                Some("OBJECTS") => {}
+                Some("OBJECTS") => {
+                    drawing.objects = section.nodes.iter().map(FromNode::from_node).collect();
+                }
+                Some("ACDSDATA") => acds_data = Some(section),
                 Some(unknown) => {
                     println!("unknown section: {}", unknown);
                 }
@@ -39,6 +46,9 @@ impl Document {
                 }
             }
         }
+        if let Some(section) = acds_data {
+            acis::attach_acds_data(section, &mut drawing);
+        }
         drawing
     }
 }
@@ -47,7 +57,7 @@ impl Document {
 #[error("{:?}", self)]
 pub struct EncodingError;
 
-pub fn bytes_to_string(bytes: &[u8]) -> Result<Cow<str>, EncodingError> {
+pub fn bytes_to_string(bytes: &[u8]) -> Result<Cow<'_, str>, EncodingError> {
     match std::str::from_utf8(bytes) {
         Ok(s) => Ok(s.into()),
         Err(_) => {
@@ -161,3 +171,25 @@ fn parse_optional_coord_to(s: &str, i: usize, dst: &mut Option<[f64; 3]>) -> boo
         false
     }
 }
+
+// appends the bytes of binary chunk data (group codes 310-319)
+fn hex_to_bytes(s: &str, dst: &mut Vec<u8>) -> bool {
+    let bytes = s
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [h, l] => Some((hex_digit(*h)? << 4) | hex_digit(*l)?),
+            _ => None,
+        })
+        .collect::<Option<Vec<u8>>>();
+    if let Some(bytes) = bytes {
+        dst.extend(bytes);
+        true
+    } else {
+        false
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
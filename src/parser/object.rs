@@ -0,0 +1,502 @@
+use super::{FromNode, SetAtom};
+use crate::*;
+
+impl FromNode for ObjectNode {
+    fn from_node(source: &Node) -> Self {
+        match source.node_type.as_ref() {
+            "DICTIONARY" => parse_by(source, Object::Dictionary),
+            "XRECORD" => parse_by(source, Object::XRecord),
+            "IMAGEDEF" => parse_by(source, Object::ImageDef),
+            "MLINESTYLE" => parse_by(source, Object::MLineStyle),
+            "LAYOUT" => parse_by(source, |builder: LayoutBuilder| {
+                Object::Layout(Box::new(builder.target))
+            }),
+            _ => parse_by(source, |atoms| {
+                Object::NotSupported((*source.node_type).to_owned(), atoms)
+            }),
+        }
+    }
+}
+fn parse_by<T: SetAtom>(source: &Node, f: impl Fn(T) -> Object) -> ObjectNode {
+    let mut header = ObjectHeader::default();
+    let mut object = T::default();
+    let mut group: Option<&str> = None;
+    let mut has_subclass = false; // owner handle precedes the first subclass marker
+    for atom in source.atoms.iter() {
+        match (atom.code, group) {
+            // application-defined groups such as "{ACAD_REACTORS" ... "}"
+            (102, None) if !has_subclass && atom.value.starts_with('{') => {
+                group = Some(&atom.value)
+            }
+            (102, Some(_)) if atom.value.starts_with('}') => group = None,
+            (_, Some(_)) => {}
+            (5, None) => {
+                if let Ok(handle) = u32::from_str_radix(&atom.value, 16) {
+                    header.handle = handle;
+                }
+            }
+            (330, None) if !has_subclass => {
+                if let Ok(handle) = u32::from_str_radix(&atom.value, 16) {
+                    header.owner_handle = handle;
+                }
+            }
+            _ => {
+                has_subclass |= atom.code == 100;
+                object.set_atom(atom);
+            }
+        }
+    }
+    object.add_nodes(&source.nodes);
+    ObjectNode {
+        header,
+        object: f(object),
+    }
+}
+
+impl SetAtom for Dictionary {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            280 => super::parse_and_then_to(value, &mut self.is_hard_owner, |x: i16| Some(x != 0)),
+            281 => super::parse_to(value, &mut self.cloning_flag),
+            3 => {
+                self.entries.push((value.to_string(), 0));
+                true
+            }
+            350 | 360 => match self.entries.last_mut() {
+                Some((_, handle)) => super::parse_and_then_to(value, handle, |s: String| {
+                    u32::from_str_radix(&s, 16).ok()
+                }),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for XRecord {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        match atom.code {
+            100 => true,
+            // the cloning flag precedes the data
+            280 if self.cloning_flag.is_none() && self.data.is_empty() => {
+                super::parse_to_option(&atom.value, &mut self.cloning_flag)
+            }
+            _ => {
+                self.data.push(atom.clone().into_owned());
+                true
+            }
+        }
+    }
+}
+
+impl SetAtom for ImageDef {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            90 => super::parse_to(value, &mut self.class_version),
+            1 => super::parse_to(value, &mut self.file_name),
+            10 => super::parse_to(value, &mut self.image_size[0]),
+            20 => super::parse_to(value, &mut self.image_size[1]),
+            11 => super::parse_to(value, &mut self.pixel_size[0]),
+            21 => super::parse_to(value, &mut self.pixel_size[1]),
+            280 => super::parse_and_then_to(value, &mut self.is_loaded, |x: i16| Some(x != 0)),
+            281 => super::parse_to(value, &mut self.resolution_units),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for MLineStyle {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            2 => super::parse_to(value, &mut self.name),
+            70 => super::parse_to(value, &mut self.flags),
+            3 => super::parse_to(value, &mut self.description),
+            51 => super::parse_to(value, &mut self.start_angle_degree),
+            52 => super::parse_to(value, &mut self.end_angle_degree),
+            71 => value.parse::<usize>().is_ok(), // the number of elements
+            49 => {
+                let mut element = MLineStyleElement::default();
+                let ok = super::parse_to(value, &mut element.offset);
+                self.elements.push(element);
+                ok
+            }
+            // 62 precedes the elements for the fill color, follows 49 for each element color
+            62 => match self.elements.last_mut() {
+                Some(element) => super::parse_to(value, &mut element.color),
+                None => super::parse_to(value, &mut self.fill_color),
+            },
+            6 => match self.elements.last_mut() {
+                Some(element) => super::parse_to(value, &mut element.line_type),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct LayoutBuilder {
+    target: Layout,
+    subclass: String,
+}
+impl SetAtom for LayoutBuilder {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        let target = &mut self.target;
+        if atom.code == 100 {
+            return super::parse_to(value, &mut self.subclass);
+        }
+        if self.subclass != "AcDbLayout" {
+            // AcDbPlotSettings is not parsed except for the page setup name
+            return match atom.code {
+                1 => super::parse_to(value, &mut target.page_setup_name),
+                _ => false,
+            };
+        }
+        match atom.code {
+            1 => super::parse_to(value, &mut target.name),
+            70 => super::parse_to(value, &mut target.flags),
+            71 => super::parse_to(value, &mut target.tab_order),
+            10 => super::parse_to(value, &mut target.limits_min[0]),
+            20 => super::parse_to(value, &mut target.limits_min[1]),
+            11 => super::parse_to(value, &mut target.limits_max[0]),
+            21 => super::parse_to(value, &mut target.limits_max[1]),
+            12 => super::parse_to(value, &mut target.insertion_base[0]),
+            22 => super::parse_to(value, &mut target.insertion_base[1]),
+            32 => super::parse_to(value, &mut target.insertion_base[2]),
+            14 => super::parse_to(value, &mut target.extents_min[0]),
+            24 => super::parse_to(value, &mut target.extents_min[1]),
+            34 => super::parse_to(value, &mut target.extents_min[2]),
+            15 => super::parse_to(value, &mut target.extents_max[0]),
+            25 => super::parse_to(value, &mut target.extents_max[1]),
+            35 => super::parse_to(value, &mut target.extents_max[2]),
+            146 => super::parse_to(value, &mut target.elevation),
+            13 => super::parse_to(value, &mut target.ucs_origin[0]),
+            23 => super::parse_to(value, &mut target.ucs_origin[1]),
+            33 => super::parse_to(value, &mut target.ucs_origin[2]),
+            16 => super::parse_to(value, &mut target.ucs_x_axis[0]),
+            26 => super::parse_to(value, &mut target.ucs_x_axis[1]),
+            36 => super::parse_to(value, &mut target.ucs_x_axis[2]),
+            17 => super::parse_to(value, &mut target.ucs_y_axis[0]),
+            27 => super::parse_to(value, &mut target.ucs_y_axis[1]),
+            37 => super::parse_to(value, &mut target.ucs_y_axis[2]),
+            330 => {
+                if let Ok(handle) = u32::from_str_radix(value, 16) {
+                    target.block_record_handle = handle;
+                    true
+                } else {
+                    false
+                }
+            }
+            331 => {
+                target.last_active_viewport_handle = u32::from_str_radix(value, 16).ok();
+                target.last_active_viewport_handle.is_some()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test_referenced_image_files() {
+    let dxf = "  0
+SECTION
+  2
+ENTITIES
+  0
+IMAGE
+  5
+2A
+  8
+0
+ 10
+1.0
+ 20
+2.0
+ 30
+0.0
+ 11
+0.5
+ 21
+0.0
+ 31
+0.0
+ 12
+0.0
+ 22
+0.5
+ 32
+0.0
+ 13
+640
+ 23
+480
+340
+2B
+ 70
+7
+280
+1
+281
+60
+ 71
+1
+ 91
+2
+ 14
+-0.5
+ 24
+-0.5
+ 14
+639.5
+ 24
+479.5
+  0
+ENDSEC
+  0
+SECTION
+  2
+OBJECTS
+  0
+IMAGEDEF
+  5
+2B
+102
+{ACAD_REACTORS
+330
+2C
+102
+}
+330
+2D
+ 90
+0
+  1
+images/site.png
+ 10
+640
+ 20
+480
+ 11
+1.0
+ 21
+1.0
+280
+1
+281
+0
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let image = match &doc.entities[0].entity {
+        Entity::Image(image) => image,
+        _ => panic!("not an image"),
+    };
+    assert_eq!(image.image_def_handle, 0x2B);
+    assert_eq!(image.brightness, 60);
+    assert!(image.display_flags.use_clipping_boundary);
+    assert_eq!(image.clip_boundary_vertices, [[-0.5, -0.5], [639.5, 479.5]]);
+    let node = doc.find_object(0x2B).unwrap();
+    assert_eq!(node.header.owner_handle, 0x2D);
+    assert_eq!(doc.referenced_image_files(), ["images/site.png"]);
+}
+
+#[test]
+fn test_layouts() {
+    let dxf = "  0
+SECTION
+  2
+TABLES
+  0
+TABLE
+  2
+LAYER
+  5
+2
+  0
+LAYER
+  5
+10
+  2
+Walls
+ 70
+0
+ 62
+7
+  6
+Continuous
+  0
+ENDTAB
+  0
+TABLE
+  2
+BLOCK_RECORD
+  5
+1
+  0
+BLOCK_RECORD
+  5
+1F
+  2
+*Model_Space
+  0
+BLOCK_RECORD
+  5
+1E
+  2
+*Paper_Space
+  0
+ENDTAB
+  0
+ENDSEC
+  0
+SECTION
+  2
+ENTITIES
+  0
+LINE
+  5
+30
+  8
+Walls
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 11
+10.0
+ 21
+0.0
+ 31
+0.0
+  0
+VIEWPORT
+  5
+31
+  8
+0
+ 67
+1
+ 10
+100.0
+ 20
+50.0
+ 30
+0.0
+ 40
+200.0
+ 41
+100.0
+ 68
+1
+ 69
+2
+ 12
+5.0
+ 22
+0.0
+ 45
+10.0
+ 51
+15.0
+331
+10
+ 90
+32864
+  1
+monochrome.ctb
+  0
+ENDSEC
+  0
+SECTION
+  2
+OBJECTS
+  0
+LAYOUT
+  5
+40
+100
+AcDbPlotSettings
+  1
+Letter
+100
+AcDbLayout
+  1
+Layout1
+ 70
+1
+ 71
+1
+ 10
+0.0
+ 20
+0.0
+ 11
+279.4
+ 21
+215.9
+330
+1E
+331
+31
+  0
+LAYOUT
+  5
+41
+100
+AcDbPlotSettings
+  1
+
+100
+AcDbLayout
+  1
+Model
+ 70
+1
+ 71
+0
+330
+1F
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let viewport = match &doc.entities[1].entity {
+        Entity::Viewport(viewport) => viewport,
+        _ => panic!("not a viewport"),
+    };
+    assert_eq!(viewport.center, [100.0, 50.0, 0.0]);
+    assert_eq!((viewport.width, viewport.height), (200.0, 100.0));
+    assert_eq!((viewport.status, viewport.id), (1, 2));
+    assert_eq!(viewport.view_center, [5.0, 0.0]);
+    assert_eq!(viewport.view_height, 10.0);
+    assert_eq!(viewport.twist_degree, 15.0);
+    assert_eq!(viewport.status_flags, 32864);
+    assert_eq!(viewport.plot_style_sheet, "monochrome.ctb");
+    assert_eq!(doc.frozen_layer_names(viewport), ["Walls"]);
+
+    let layouts = doc.layouts();
+    assert_eq!(layouts.len(), 2);
+    let (model, paper) = (&layouts[0], &layouts[1]);
+    assert_eq!(model.layout.name, "Model");
+    assert!(model.is_model_space());
+    assert_eq!(model.entities().len(), 1);
+    assert!(model.viewports().is_empty());
+    assert_eq!(paper.handle, 0x40);
+    assert_eq!(paper.layout.page_setup_name, "Letter");
+    assert_eq!(paper.layout.limits_max, [279.4, 215.9]);
+    assert_eq!(paper.layout.last_active_viewport_handle, Some(0x31));
+    assert_eq!(paper.block_name(), Some("*Paper_Space"));
+    assert!(!paper.is_model_space());
+    assert_eq!(paper.viewports().len(), 1);
+    assert_eq!(paper.viewports()[0].0.handle, 0x31);
+    assert!(doc.layout("Layout1").is_some());
+}
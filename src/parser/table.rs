@@ -1,6 +1,6 @@
 use crate::*;
 
-use super::FromNode;
+use super::{FromNode, SetAtom};
 
 impl FromNode for TableNode {
     fn from_node(source: &Node) -> Self {
@@ -37,27 +37,15 @@ impl FromNode for TableEntry {
             .map(|a| a.value.to_string())
             .unwrap_or_default();
         let record = match &*source.node_type {
-            // "APPID" => {
-            //     unimplemented!()
-            // }
-            // "BLOCK_RECORD" => {
-            //     unimplemented!()
-            // }
+            "APPID" => TableRecord::RegApp(FromNode::from_node(source)),
+            "BLOCK_RECORD" => TableRecord::Block(FromNode::from_node(source)),
             "DIMSTYLE" => TableRecord::DimStyle(Box::new(FromNode::from_node(source))),
             "LAYER" => TableRecord::Layer(FromNode::from_node(source)),
             "LTYPE" => TableRecord::LineType(FromNode::from_node(source)),
-            // "STYLE" => {
-            //     unimplemented!()
-            // }
-            // "UCS" => {
-            //     unimplemented!()
-            // }
-            // "VIEW" => {
-            //     unimplemented!()
-            // }
-            // "VPORT" => {
-            //     unimplemented!()
-            // }
+            "STYLE" => TableRecord::TextStyle(FromNode::from_node(source)),
+            "UCS" => TableRecord::Ucs(FromNode::from_node(source)),
+            "VIEW" => TableRecord::View(FromNode::from_node(source)),
+            "VPORT" => TableRecord::Viewport(FromNode::from_node(source)),
             _ => TableRecord::NotSupported(source.to_owned()),
         };
         Self {
@@ -68,63 +56,132 @@ impl FromNode for TableEntry {
     }
 }
 
-impl FromNode for DimStyle {
-    fn from_node(source: &Node) -> Self {
-        assert_eq!(source.node_type, "DIMSTYLE");
-        let mut dst = DimStyle::default();
+impl SetAtom for RegApp {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        match atom.code {
+            70 => super::parse_and_then_to(&atom.value, &mut self.flags, |x: i16| Some(x as u16)),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Block {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            340 => parse_handle(value, &mut self.layout_handle),
+            70 => super::parse_to(value, &mut self.insert_units),
+            280 => super::parse_and_then_to(value, &mut self.is_explodable, |x: i16| Some(x != 0)),
+            281 => super::parse_and_then_to(value, &mut self.is_scalable, |x: i16| Some(x != 0)),
+            310 => super::hex_to_bytes(value, &mut self.preview),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for DimStyle {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
         let int2bool = |x: i16| match x {
             0 => Some(false),
             1 => Some(true),
             _ => None,
         };
-        for atom in source.atoms.iter() {
-            let _ = match atom.code {
-                3 => super::parse_to(&atom.value, &mut dst.general_dimensioning_suffix),
-                4 => super::parse_to(&atom.value, &mut dst.alternate_dimensioning_suffix),
-                5 => super::parse_to(&atom.value, &mut dst.arrow_block_name),
-                6 => super::parse_to(&atom.value, &mut dst.arrow1_block_name),
-                7 => super::parse_to(&atom.value, &mut dst.arrow2_block_name),
-                40 => super::parse_to(&atom.value, &mut dst.scale_factor),
-                41 => super::parse_to(&atom.value, &mut dst.arrow_size),
-                42 => super::parse_to(&atom.value, &mut dst.extension_line_offset),
-                43 => super::parse_to(&atom.value, &mut dst.dimension_line_increment),
-                44 => super::parse_to(&atom.value, &mut dst.extension_line_extension),
-                45 => super::parse_to(&atom.value, &mut dst.rounding_value),
-                46 => super::parse_to(&atom.value, &mut dst.dimension_line_extension),
-                47 => super::parse_to(&atom.value, &mut dst.plus_tolerance),
-                48 => super::parse_to(&atom.value, &mut dst.minus_tolerance),
-                140 => super::parse_to(&atom.value, &mut dst.text_height),
-                141 => super::parse_to(&atom.value, &mut dst.center_mark_size),
-                142 => super::parse_to(&atom.value, &mut dst.tick_size),
-                143 => super::parse_to(&atom.value, &mut dst.alternate_unit_scale_factor),
-                144 => super::parse_to(&atom.value, &mut dst.linear_measurement_scale_factor),
-                145 => super::parse_to(&atom.value, &mut dst.text_vertical_position),
-                146 => super::parse_to(&atom.value, &mut dst.tolerance_display_scale_factor),
-                147 => super::parse_to(&atom.value, &mut dst.dimension_line_gap),
-                148 => super::parse_to(&atom.value, &mut dst.alternate_unit_rounding),
-                71 => super::parse_to(&atom.value, &mut dst.tolerance),
-                72 => super::parse_to(&atom.value, &mut dst.dimension_limits),
-                73 => super::parse_to(&atom.value, &mut dst.text_inside_horizontal),
-                74 => super::parse_to(&atom.value, &mut dst.text_outside_horizontal),
-                75 => super::parse_and_then_to(
-                    &atom.value,
-                    &mut dst.extension_line1_suppressed,
-                    int2bool,
-                ),
-                76 => super::parse_and_then_to(
-                    &atom.value,
-                    &mut dst.extension_line2_suppressed,
-                    int2bool,
-                ),
-                77 => super::parse_and_then_to(
-                    &atom.value,
-                    &mut dst.text_above_dimension_line,
-                    int2bool,
-                ),
-                _ => false,
-            };
+        match atom.code {
+            3 => super::parse_to(value, &mut self.general_dimensioning_suffix),
+            4 => super::parse_to(value, &mut self.alternate_dimensioning_suffix),
+            5 => super::parse_to(value, &mut self.arrow_block_name),
+            6 => super::parse_to(value, &mut self.arrow1_block_name),
+            7 => super::parse_to(value, &mut self.arrow2_block_name),
+            40 => super::parse_to(value, &mut self.scale_factor),
+            41 => super::parse_to(value, &mut self.arrow_size),
+            42 => super::parse_to(value, &mut self.extension_line_offset),
+            43 => super::parse_to(value, &mut self.dimension_line_increment),
+            44 => super::parse_to(value, &mut self.extension_line_extension),
+            45 => super::parse_to(value, &mut self.rounding_value),
+            46 => super::parse_to(value, &mut self.dimension_line_extension),
+            47 => super::parse_to(value, &mut self.plus_tolerance),
+            48 => super::parse_to(value, &mut self.minus_tolerance),
+            140 => super::parse_to(value, &mut self.text_height),
+            141 => super::parse_to(value, &mut self.center_mark_size),
+            142 => super::parse_to(value, &mut self.tick_size),
+            143 => super::parse_to(value, &mut self.alternate_unit_scale_factor),
+            144 => super::parse_to(value, &mut self.linear_measurement_scale_factor),
+            145 => super::parse_to(value, &mut self.text_vertical_position),
+            146 => super::parse_to(value, &mut self.tolerance_display_scale_factor),
+            147 => super::parse_to(value, &mut self.dimension_line_gap),
+            148 => super::parse_to(value, &mut self.alternate_unit_rounding),
+            71 => super::parse_to(value, &mut self.tolerance),
+            72 => super::parse_to(value, &mut self.dimension_limits),
+            73 => super::parse_to(value, &mut self.text_inside_horizontal),
+            74 => super::parse_to(value, &mut self.text_outside_horizontal),
+            75 => super::parse_and_then_to(value, &mut self.extension_line1_suppressed, int2bool),
+            76 => super::parse_and_then_to(value, &mut self.extension_line2_suppressed, int2bool),
+            77 => super::parse_and_then_to(value, &mut self.text_above_dimension_line, int2bool),
+            78 => super::parse_to(value, &mut self.zero_suppression),
+            79 => super::parse_and_then_to(value, &mut self.angular_zero_suppression, |x: i16| {
+                Some(ZeroSuppression {
+                    leading_zeros: (x & 0b01) != 0,
+                    trailing_zeros: (x & 0b10) != 0,
+                    ..Default::default()
+                })
+            }),
+            170 => super::parse_and_then_to(value, &mut self.alternate_units_enabled, int2bool),
+            171 => super::parse_to(value, &mut self.alternate_unit_decimal_places),
+            172 => super::parse_and_then_to(value, &mut self.force_dimension_line_inside, int2bool),
+            173 => super::parse_and_then_to(value, &mut self.use_separate_arrow_blocks, int2bool),
+            174 => super::parse_and_then_to(value, &mut self.force_text_inside, int2bool),
+            175 => super::parse_and_then_to(
+                value,
+                &mut self.suppress_outside_dimension_lines,
+                int2bool,
+            ),
+            176 => super::parse_to(value, &mut self.dimension_line_color),
+            177 => super::parse_to(value, &mut self.extension_line_color),
+            178 => super::parse_to(value, &mut self.text_color),
+            179 => super::parse_to(value, &mut self.angular_decimal_places),
+            270 => super::parse_to(value, &mut self.unit),
+            271 => super::parse_to(value, &mut self.decimal_places),
+            272 => super::parse_to(value, &mut self.tolerance_decimal_places),
+            273 => super::parse_to(value, &mut self.alternate_unit_format),
+            274 => super::parse_to(value, &mut self.alternate_tolerance_decimal_places),
+            275 => super::parse_to(value, &mut self.angular_unit_format),
+            276 => super::parse_to(value, &mut self.fraction_format),
+            277 => super::parse_to(value, &mut self.linear_unit_format),
+            // 0 means the default separator
+            278 => super::parse_and_then_to(value, &mut self.decimal_separator, |x: u32| {
+                Some(char::from_u32(x).filter(|&c| c != '\0'))
+            }),
+            279 => super::parse_to(value, &mut self.text_movement),
+            280 => super::parse_to(value, &mut self.text_justification),
+            281 => super::parse_and_then_to(value, &mut self.dimension_line1_suppressed, int2bool),
+            282 => super::parse_and_then_to(value, &mut self.dimension_line2_suppressed, int2bool),
+            283 => super::parse_to(value, &mut self.tolerance_alignment),
+            284 => super::parse_to(value, &mut self.tolerance_zero_suppression),
+            285 => super::parse_to(value, &mut self.alternate_zero_suppression),
+            286 => super::parse_to(value, &mut self.alternate_tolerance_zero_suppression),
+            287 => super::parse_to(value, &mut self.fit),
+            288 => super::parse_and_then_to(value, &mut self.cursor_updates_text, int2bool),
+            289 => super::parse_to(value, &mut self.arrow_text_fit),
+            340 => parse_handle(value, &mut self.text_style_handle),
+            341 => parse_handle(value, &mut self.leader_arrow_block_handle),
+            342 => parse_handle(value, &mut self.arrow_block_handle),
+            343 => parse_handle(value, &mut self.arrow1_block_handle),
+            344 => parse_handle(value, &mut self.arrow2_block_handle),
+            371 => super::parse_to(value, &mut self.dimension_line_weight),
+            372 => super::parse_to(value, &mut self.extension_line_weight),
+            _ => false,
+        }
+    }
+}
+
+impl DimStyle {
+    /// Applies overrides given as pairs of a DIMSTYLE group code and its value,
+    /// such as `Dimension::style_overrides`.
+    pub fn apply_overrides(&mut self, overrides: &[Atom]) {
+        for atom in overrides {
+            self.set_atom(atom);
         }
-        dst
     }
 }
 
@@ -135,32 +192,249 @@ impl FromNode for Layer {
             is_plotted: true,
             ..Layer::default()
         };
+        let mut group: Option<&str> = None;
+        let mut xdata_app: Option<&str> = None;
         for atom in source.atoms.iter() {
-            match atom.code {
-                70 => {
-                    super::parse_and_then_to(&atom.value, &mut dst.flags, |x: i16| Some(x as _));
+            let value = &atom.value;
+            let _ = match (atom.code, group) {
+                (102, None) if value.starts_with('{') => {
+                    group = Some(value);
+                    true
+                }
+                (102, Some(_)) => {
+                    group = None;
+                    true
                 }
-                62 => {
+                (360, Some("{ACAD_XDICTIONARY")) => {
+                    parse_handle(value, &mut dst.extension_dictionary_handle)
+                }
+                (_, Some(_)) => false,
+                (70, _) => super::parse_to(value, &mut dst.flags),
+                (62, _) => {
                     // if negative, layer is off
-                    dst.color_number = atom
-                        .value
-                        .parse::<i16>()
-                        .ok()
-                        .filter(|&c| c >= 0)
-                        .map(|c| c as u8);
+                    if let Ok(c) = value.parse::<i16>() {
+                        dst.is_off = c < 0;
+                        dst.color_index = c.unsigned_abs() as u8;
+                        dst.color_number = (c >= 0).then_some(dst.color_index);
+                        true
+                    } else {
+                        false
+                    }
                 }
-                6 => dst.line_type = atom.value.parse().ok(),
-                290 => dst.is_plotted = atom.value.parse::<i16>().unwrap_or_default() != 0,
-                370 => dst.line_weight = atom.value.parse::<i16>().ok(),
-                390 => dst.plot_style_handle = u32::from_str_radix(&atom.value, 16).ok(),
-                347 => dst.material_handle = u32::from_str_radix(&atom.value, 16).ok(),
-                _ => {}
-            }
+                (420, _) => super::parse_to_option(value, &mut dst.color_rgb),
+                (6, _) => super::parse_to_option(value, &mut dst.line_type),
+                (290, _) => {
+                    super::parse_and_then_to(value, &mut dst.is_plotted, |x: i16| Some(x != 0))
+                }
+                (370, _) => super::parse_to_option(value, &mut dst.line_weight),
+                (390, _) => parse_handle(value, &mut dst.plot_style_handle),
+                (347, _) => parse_handle(value, &mut dst.material_handle),
+                (1001, _) => {
+                    xdata_app = Some(value);
+                    true
+                }
+                (1071, _) if xdata_app == Some("AcCmTransparency") => {
+                    super::parse_to_option(value, &mut dst.transparency)
+                }
+                _ => false,
+            };
+        }
+        dst
+    }
+}
+
+impl FromNode for TextStyle {
+    fn from_node(source: &Node) -> Self {
+        assert_eq!(source.node_type, "STYLE");
+        let mut dst = TextStyle {
+            font_file_name: String::default(),
+            ..TextStyle::default()
+        };
+        let mut is_acad_xdata = false;
+        for atom in source.atoms.iter() {
+            let value = &atom.value;
+            let _ = match atom.code {
+                70 => super::parse_and_then_to(value, &mut dst.flags, |x: i16| Some(x as u16)),
+                40 => super::parse_to(value, &mut dst.fixed_height),
+                41 => super::parse_to(value, &mut dst.width_factor),
+                50 => super::parse_to(value, &mut dst.oblique_degree),
+                71 => super::parse_to(value, &mut dst.generation_flags),
+                42 => super::parse_to(value, &mut dst.last_height),
+                3 => super::parse_to(value, &mut dst.font_file_name),
+                4 => super::parse_to(value, &mut dst.big_font_file_name),
+                1001 => {
+                    is_acad_xdata = value == "ACAD";
+                    true
+                }
+                1000 if is_acad_xdata => super::parse_to_option(value, &mut dst.true_type_family),
+                1071 if is_acad_xdata => super::parse_to_option(value, &mut dst.true_type_flags),
+                _ => false,
+            };
         }
         dst
     }
 }
 
+impl SetAtom for Ucs {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            70 => super::parse_and_then_to(value, &mut self.flags, |x: i16| Some(x as u16)),
+            10 => super::parse_to(value, &mut self.origin[0]),
+            20 => super::parse_to(value, &mut self.origin[1]),
+            30 => super::parse_to(value, &mut self.origin[2]),
+            11 => super::parse_to(value, &mut self.x_axis[0]),
+            21 => super::parse_to(value, &mut self.x_axis[1]),
+            31 => super::parse_to(value, &mut self.x_axis[2]),
+            12 => super::parse_to(value, &mut self.y_axis[0]),
+            22 => super::parse_to(value, &mut self.y_axis[1]),
+            32 => super::parse_to(value, &mut self.y_axis[2]),
+            146 => super::parse_to(value, &mut self.elevation),
+            346 => parse_handle(value, &mut self.base_ucs_handle),
+            71 => {
+                if let Ok(orthographic_type) = value.parse() {
+                    self.orthographic_origins
+                        .push((orthographic_type, [0.0, 0.0, 0.0]));
+                    true
+                } else {
+                    false
+                }
+            }
+            13 | 23 | 33 => match self.orthographic_origins.last_mut() {
+                Some((_, origin)) => {
+                    super::parse_to(value, &mut origin[atom.code as usize / 10 - 1])
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for ViewUcs {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            110 => super::parse_to(value, &mut self.origin[0]),
+            120 => super::parse_to(value, &mut self.origin[1]),
+            130 => super::parse_to(value, &mut self.origin[2]),
+            111 => super::parse_to(value, &mut self.x_axis[0]),
+            121 => super::parse_to(value, &mut self.x_axis[1]),
+            131 => super::parse_to(value, &mut self.x_axis[2]),
+            112 => super::parse_to(value, &mut self.y_axis[0]),
+            122 => super::parse_to(value, &mut self.y_axis[1]),
+            132 => super::parse_to(value, &mut self.y_axis[2]),
+            79 => super::parse_to(value, &mut self.orthographic_type),
+            146 => super::parse_to(value, &mut self.elevation),
+            345 => parse_handle(value, &mut self.named_ucs_handle),
+            346 => parse_handle(value, &mut self.base_ucs_handle),
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for View {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            70 => super::parse_and_then_to(value, &mut self.flags, |x: i16| Some(x as u16)),
+            10 => super::parse_to(value, &mut self.center[0]),
+            20 => super::parse_to(value, &mut self.center[1]),
+            40 => super::parse_to(value, &mut self.height),
+            41 => super::parse_to(value, &mut self.width),
+            11 => super::parse_to(value, &mut self.view_direction[0]),
+            21 => super::parse_to(value, &mut self.view_direction[1]),
+            31 => super::parse_to(value, &mut self.view_direction[2]),
+            12 => super::parse_to(value, &mut self.target[0]),
+            22 => super::parse_to(value, &mut self.target[1]),
+            32 => super::parse_to(value, &mut self.target[2]),
+            42 => super::parse_to(value, &mut self.lens_length),
+            43 => super::parse_to(value, &mut self.front_clip_z),
+            44 => super::parse_to(value, &mut self.back_clip_z),
+            50 => super::parse_to(value, &mut self.twist_degree),
+            71 => super::parse_to(value, &mut self.view_mode),
+            281 => super::parse_to(value, &mut self.render_mode),
+            73 => super::parse_and_then_to(value, &mut self.is_camera_plottable, |x: i16| {
+                Some(x != 0)
+            }),
+            332 => parse_handle(value, &mut self.background_handle),
+            334 => parse_handle(value, &mut self.live_section_handle),
+            348 => parse_handle(value, &mut self.visual_style_handle),
+            72 => {
+                if value.trim() == "1" {
+                    self.ucs.get_or_insert_with(ViewUcs::default);
+                }
+                true
+            }
+            110..=132 | 79 | 146 | 345 | 346 => {
+                self.ucs.get_or_insert_with(ViewUcs::default).set_atom(atom)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SetAtom for Viewport {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        let int2bool = |x: i16| Some(x != 0);
+        match atom.code {
+            70 => super::parse_and_then_to(value, &mut self.flags, |x: i16| Some(x as u16)),
+            10 => super::parse_to(value, &mut self.lower_left[0]),
+            20 => super::parse_to(value, &mut self.lower_left[1]),
+            11 => super::parse_to(value, &mut self.upper_right[0]),
+            21 => super::parse_to(value, &mut self.upper_right[1]),
+            12 => super::parse_to(value, &mut self.view_center[0]),
+            22 => super::parse_to(value, &mut self.view_center[1]),
+            13 => super::parse_to(value, &mut self.snap_base_point[0]),
+            23 => super::parse_to(value, &mut self.snap_base_point[1]),
+            14 => super::parse_to(value, &mut self.snap_spacing[0]),
+            24 => super::parse_to(value, &mut self.snap_spacing[1]),
+            15 => super::parse_to(value, &mut self.grid_spacing[0]),
+            25 => super::parse_to(value, &mut self.grid_spacing[1]),
+            16 => super::parse_to(value, &mut self.view_direction[0]),
+            26 => super::parse_to(value, &mut self.view_direction[1]),
+            36 => super::parse_to(value, &mut self.view_direction[2]),
+            17 => super::parse_to(value, &mut self.view_target[0]),
+            27 => super::parse_to(value, &mut self.view_target[1]),
+            37 => super::parse_to(value, &mut self.view_target[2]),
+            40 | 45 => super::parse_to(value, &mut self.view_height),
+            41 => super::parse_to(value, &mut self.aspect_ratio),
+            42 => super::parse_to(value, &mut self.lens_length),
+            43 => super::parse_to(value, &mut self.front_clip_z),
+            44 => super::parse_to(value, &mut self.back_clip_z),
+            50 => super::parse_to(value, &mut self.snap_rotation_degree),
+            51 => super::parse_to(value, &mut self.twist_degree),
+            71 => super::parse_to(value, &mut self.view_mode),
+            72 => super::parse_to(value, &mut self.circle_zoom_percent),
+            73 => super::parse_and_then_to(value, &mut self.fast_zoom, int2bool),
+            74 => super::parse_to(value, &mut self.ucs_icon),
+            75 => super::parse_and_then_to(value, &mut self.snap_on, int2bool),
+            76 => super::parse_and_then_to(value, &mut self.grid_on, int2bool),
+            77 => super::parse_to(value, &mut self.snap_style),
+            78 => super::parse_to(value, &mut self.snap_isopair),
+            281 => super::parse_to(value, &mut self.render_mode),
+            65 => super::parse_and_then_to(value, &mut self.ucs_per_viewport, int2bool),
+            60 => super::parse_to(value, &mut self.grid_flags),
+            61 => super::parse_to(value, &mut self.grid_major),
+            332 => parse_handle(value, &mut self.background_handle),
+            348 => parse_handle(value, &mut self.visual_style_handle),
+            _ => self.ucs.set_atom(atom),
+        }
+    }
+}
+
+// "0" is the null handle
+fn parse_handle(value: &str, dst: &mut Option<u32>) -> bool {
+    match u32::from_str_radix(value, 16) {
+        Ok(handle) => {
+            *dst = Some(handle).filter(|&h| h != 0);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
 impl FromNode for LineType {
     fn from_node(source: &Node) -> Self {
         assert_eq!(source.node_type, "LTYPE");
@@ -173,16 +447,110 @@ impl FromNode for LineType {
                 3 => super::parse_to(&atom.value, &mut dst.description),
                 40 => super::parse_to(&atom.value, &mut dst.total_pattern_length),
                 49 => {
-                    if let Ok(len) = atom.value.parse() {
-                        dst.pattern_lengths.push(len);
-                        true
-                    } else {
-                        false
-                    }
+                    let mut element = LineTypeElement::default();
+                    let ok = super::parse_to(&atom.value, &mut element.length);
+                    dst.pattern_lengths.push(element.length);
+                    dst.elements.push(element);
+                    ok
                 }
-                _ => false,
+                _ => match dst.elements.last_mut() {
+                    Some(element) => element.set_atom(atom),
+                    None => false,
+                },
             };
         }
         dst
     }
 }
+
+impl SetAtom for LineTypeElement {
+    fn set_atom(&mut self, atom: &Atom) -> bool {
+        let value = &atom.value;
+        match atom.code {
+            74 => super::parse_to(value, &mut self.flags),
+            75 => super::parse_to(value, &mut self.shape_number),
+            340 => parse_handle(value, &mut self.style_handle),
+            46 => super::parse_to(value, &mut self.scale),
+            50 => super::parse_to(value, &mut self.rotation_degree),
+            44 => super::parse_to(value, &mut self.offset[0]),
+            45 => super::parse_to(value, &mut self.offset[1]),
+            9 => super::parse_to_option(value, &mut self.text),
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test_complex_line_type() {
+    let dxf = "  0
+SECTION
+  2
+TABLES
+  0
+TABLE
+  2
+LTYPE
+  5
+5
+  0
+LTYPE
+  5
+3A
+  2
+GAS_LINE
+ 70
+0
+  3
+Gas line ----GAS----GAS----
+ 72
+65
+ 73
+3
+ 40
+0.7
+ 49
+0.5
+ 74
+0
+ 49
+-0.2
+ 74
+2
+ 75
+0
+340
+11
+ 46
+0.1
+ 50
+0.0
+ 44
+-0.1
+ 45
+-0.05
+  9
+$GAS
+ 49
+-0.25
+ 74
+0
+  0
+ENDTAB
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let line_type = match &doc.tables[0].entries[0].record {
+        TableRecord::LineType(line_type) => line_type,
+        _ => panic!("not a linetype"),
+    };
+    assert_eq!(line_type.pattern_lengths, [0.5, -0.2, -0.25]);
+    assert_eq!(line_type.elements.len(), 3);
+    let text = &line_type.elements[1];
+    assert!(text.flags.is_text && !text.flags.is_rotation_absolute);
+    assert_eq!(text.style_handle, Some(0x11));
+    assert_eq!(text.scale, 0.1);
+    assert_eq!(text.offset, [-0.1, -0.05]);
+    assert_eq!(text.text.as_deref(), Some("$GAS"));
+}
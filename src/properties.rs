@@ -0,0 +1,158 @@
+use crate::*;
+
+/// Display properties of an entity after resolving ByLayer and ByBlock.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EffectiveProperties {
+    pub layer: String, // layer "0" inside a block takes the layer of the INSERT
+    pub color_number: u8,
+    pub color_rgb: Option<Rgb>, // true color, takes precedence over `color_number`
+    pub line_type: String,
+    pub line_weight: i16,     // in 1/100 mm
+    pub line_type_scale: f64, // of the entity, without $LTSCALE
+    pub transparency: f64,    // 0 = opaque, 1 = fully transparent
+    pub is_visible: bool,
+}
+
+// raw 440 values
+const TRANSPARENCY_BY_BLOCK: i32 = 0x0100_0000;
+
+fn transparency_from_raw(raw: i32) -> f64 {
+    1.0 - (raw & 0xff) as f64 / 255.0
+}
+
+impl Document {
+    /// Resolves the properties of an entity.
+    /// `inserts` are the headers of the INSERT entities through which the entity is referenced,
+    /// from the outermost to the innermost; empty for entities not in a block.
+    pub fn effective_properties(
+        &self,
+        entity: &EntityHeader,
+        inserts: &[&EntityHeader],
+    ) -> EffectiveProperties {
+        let parent = inserts
+            .split_last()
+            .map(|(insert, rest)| self.effective_properties(insert, rest));
+        let layer_name = match &parent {
+            Some(parent) if entity.layer == "0" => parent.layer.clone(),
+            _ => entity.layer.clone(),
+        };
+        let layer = self.layer(&layer_name);
+
+        let (color_number, color_rgb) = match (entity.color_rgb, entity.color_number) {
+            (Some(rgb), ColorNumber::Number(n)) => (n, Some(rgb)),
+            (Some(rgb), _) => (rgb.nearest_aci(), Some(rgb)),
+            (None, ColorNumber::Number(n)) => (n, None),
+            (None, ColorNumber::ByBlock) => match &parent {
+                Some(parent) => (parent.color_number, parent.color_rgb),
+                None => (7, None),
+            },
+            (None, _) => match layer {
+                Some(layer) => (layer.color_index, layer.color_rgb),
+                None => (7, None),
+            },
+        };
+
+        let layer_line_type = || {
+            layer
+                .and_then(|layer| layer.line_type.clone())
+                .unwrap_or_else(|| "Continuous".to_owned())
+        };
+        let line_type = match &entity.line_type {
+            LineTypeRef::ByName(name) => name.clone(),
+            LineTypeRef::ByBlock => match &parent {
+                Some(parent) => parent.line_type.clone(),
+                None => "Continuous".to_owned(),
+            },
+            LineTypeRef::ByLayer => layer_line_type(),
+        };
+
+        let default_line_weight = || {
+            self.header("$LWDEFAULT")
+                .and_then(|node| node.atoms.find(70))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(25)
+        };
+        let layer_line_weight = || match layer.and_then(|layer| layer.line_weight) {
+            Some(w) if w >= 0 => w,
+            _ => default_line_weight(),
+        };
+        let line_weight = match entity.line_weight {
+            Some(w) if w >= 0 => w,
+            Some(-2) => match &parent {
+                Some(parent) => parent.line_weight,
+                None => default_line_weight(),
+            },
+            Some(-3) => default_line_weight(),
+            _ => layer_line_weight(),
+        };
+
+        let transparency = match entity.transparency {
+            Some(TRANSPARENCY_BY_BLOCK) => parent.as_ref().map_or(0.0, |p| p.transparency),
+            Some(raw) => transparency_from_raw(raw),
+            None => layer
+                .and_then(|layer| layer.transparency)
+                .map_or(0.0, transparency_from_raw),
+        };
+
+        let is_visible = entity.is_visible
+            && layer.is_none_or(|layer| layer.is_visible())
+            && parent.as_ref().is_none_or(|parent| parent.is_visible);
+
+        EffectiveProperties {
+            layer: layer_name,
+            color_number,
+            color_rgb,
+            line_type,
+            line_weight,
+            line_type_scale: entity.line_type_scale.unwrap_or(1.0),
+            transparency,
+            is_visible,
+        }
+    }
+}
+
+#[test]
+fn test_effective_properties() {
+    let layer = |name: &str, color_index: u8| TableEntry {
+        handle: 0,
+        name: name.to_owned(),
+        record: TableRecord::Layer(Layer {
+            color_number: Some(color_index),
+            color_index,
+            line_type: Some("DASHED".to_owned()),
+            ..Default::default()
+        }),
+    };
+    let doc = Document {
+        tables: vec![TableNode {
+            handle: 2,
+            entries: vec![layer("0", 7), layer("Doors", 3)],
+        }],
+        ..Default::default()
+    };
+    let header = |layer: &str, color_number| EntityHeader {
+        layer: layer.to_owned(),
+        color_number,
+        is_visible: true,
+        ..Default::default()
+    };
+    let insert = EntityHeader {
+        color_rgb: Some(Rgb { r: 1, g: 2, b: 3 }),
+        line_type: LineTypeRef::ByName("HIDDEN".to_owned()),
+        ..header("Doors", ColorNumber::Number(5))
+    };
+    let by_layer = doc.effective_properties(&header("0", ColorNumber::ByLayer), &[&insert]);
+    assert_eq!(by_layer.layer, "Doors");
+    assert_eq!((by_layer.color_number, by_layer.color_rgb), (3, None));
+    assert_eq!(by_layer.line_type, "DASHED");
+    let by_block = EntityHeader {
+        line_type: LineTypeRef::ByBlock,
+        ..header("0", ColorNumber::ByBlock)
+    };
+    let by_block = doc.effective_properties(&by_block, &[&insert]);
+    assert_eq!(by_block.color_number, 5);
+    assert_eq!(by_block.color_rgb, Some(Rgb { r: 1, g: 2, b: 3 }));
+    assert_eq!(by_block.line_type, "HIDDEN");
+    assert_eq!(by_block.line_weight, 25);
+    assert!(by_block.is_visible);
+}
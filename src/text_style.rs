@@ -0,0 +1,172 @@
+use crate::*;
+
+/// Font parameters of a TEXT or MTEXT entity, resolved through its text style.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TextFont {
+    pub style_name: String,
+    pub font_file_name: String,
+    pub big_font_file_name: String,
+    pub true_type_family: Option<String>,
+    pub height: f64,
+    pub width_factor: f64,
+    pub oblique_degree: f64,
+    pub mirror_flags: TextMirrorFlags,
+    pub is_vertical: bool,
+}
+
+impl TextFont {
+    fn new(style_name: &str, style: &TextStyle, height: f64) -> Self {
+        let height = if height > 0.0 {
+            height
+        } else if style.fixed_height > 0.0 {
+            style.fixed_height
+        } else {
+            style.last_height
+        };
+        Self {
+            style_name: style_name.to_owned(),
+            font_file_name: style.font_file_name.clone(),
+            big_font_file_name: style.big_font_file_name.clone(),
+            true_type_family: style.true_type_family.clone(),
+            height,
+            width_factor: style.width_factor,
+            oblique_degree: style.oblique_degree,
+            mirror_flags: style.generation_flags,
+            is_vertical: style.is_vertical(),
+        }
+    }
+}
+
+impl Document {
+    /// Finds a STYLE table record by name (case-insensitive).
+    pub fn text_style(&self, name: &str) -> Option<&TextStyle> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.entries.iter())
+            .find_map(|entry| match &entry.record {
+                TableRecord::TextStyle(style) if entry.name.eq_ignore_ascii_case(name) => {
+                    Some(style)
+                }
+                _ => None,
+            })
+    }
+
+    /// Font parameters of a TEXT entity.
+    /// Values set on the entity take precedence over those of the style.
+    pub fn text_font(&self, text: &Text) -> TextFont {
+        let style_name = text.style_name.as_deref().unwrap_or("STANDARD");
+        let default_style = TextStyle::default();
+        let style = self.text_style(style_name).unwrap_or(&default_style);
+        let mut font = TextFont::new(style_name, style, text.height);
+        if let Some(factor) = text.relative_x_scale_factor {
+            font.width_factor = factor;
+        }
+        if let Some(degree) = text.oblique_degree {
+            font.oblique_degree = degree;
+        }
+        if let Some(flags) = text.mirror_flags {
+            font.mirror_flags = flags;
+        }
+        font
+    }
+
+    /// Font parameters of an MTEXT entity.
+    /// MTEXT ignores the generation flags of the style; inline format codes are not applied.
+    pub fn mtext_font(&self, mtext: &MText) -> TextFont {
+        let style_name = mtext.style_name.as_deref().unwrap_or("STANDARD");
+        let default_style = TextStyle::default();
+        let style = self.text_style(style_name).unwrap_or(&default_style);
+        TextFont {
+            mirror_flags: TextMirrorFlags::default(),
+            ..TextFont::new(style_name, style, mtext.height)
+        }
+    }
+}
+
+#[test]
+fn test_text_font() {
+    let dxf = "  0
+SECTION
+  2
+TABLES
+  0
+TABLE
+  2
+STYLE
+  5
+3
+  0
+STYLE
+  5
+11
+  2
+Annotative
+ 70
+0
+ 40
+0.0
+ 41
+0.8
+ 50
+15.0
+ 71
+2
+ 42
+3.5
+  3
+arial.ttf
+  4
+
+1001
+ACAD
+1000
+Arial
+1071
+34
+  0
+ENDTAB
+  0
+ENDSEC
+  0
+SECTION
+  2
+ENTITIES
+  0
+TEXT
+  5
+20
+  8
+0
+ 10
+0.0
+ 20
+0.0
+ 30
+0.0
+ 40
+5.0
+  1
+abc
+  7
+annotative
+ 41
+1.2
+  0
+ENDSEC
+  0
+EOF";
+    let doc = Document::parse_str(dxf).unwrap();
+    let style = doc.text_style("ANNOTATIVE").unwrap();
+    assert_eq!(style.true_type_family.as_deref(), Some("Arial"));
+    assert_eq!(style.true_type_flags, Some(34));
+    let text = match &doc.entities[0].entity {
+        Entity::Text(text) => text,
+        _ => panic!("not a text"),
+    };
+    let font = doc.text_font(text);
+    assert_eq!(font.font_file_name, "arial.ttf");
+    assert_eq!(font.height, 5.0);
+    assert_eq!(font.width_factor, 1.2);
+    assert_eq!(font.oblique_degree, 15.0);
+    assert!(font.mirror_flags.x && !font.mirror_flags.y);
+}